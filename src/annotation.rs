@@ -0,0 +1,150 @@
+use document::DocumentHandle;
+use encoder;
+use error::Error;
+use haru;
+use std::rc::Rc;
+use types::{self, AnnotationIcon, BorderStyle, HighlightMode};
+
+/// An annotation placed on a page, such as a clickable link or a comment.
+#[derive(Clone, Debug)]
+pub struct Annotation {
+    handle: haru::HPDF_Annotation,
+    // Keep a handle to the parent document to keep it from dropping while this `Annotation` is in
+    // scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl Annotation {
+    /// Sets the style and width of the border drawn around the annotation. A width of `0.0`
+    /// hides the border.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_border_style(&mut self, style: BorderStyle,
+                            width: f32) -> Result<&mut Self, Error> {
+        let (subtype, dash_on, dash_off, dash_phase) = types::border_style_as_raw(style);
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Annotation_SetBorderStyle(self.handle, subtype, width, dash_on, dash_off,
+                                                 dash_phase)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the border of the annotation to a dashed line of the given width, consisting of
+    /// `on` units of solid line followed by `off` units of gap.
+    ///
+    /// This is a shorthand for `set_border_style` with `BorderStyle::Dashed` and a phase of `0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_border_dash(&mut self, width: f32, on: u16, off: u16) -> Result<&mut Self, Error> {
+        self.set_border_style(BorderStyle::Dashed { on: on, off: off, phase: 0 }, width)
+    }
+
+    /// Sets how the annotation is displayed while the mouse button is pressed inside of it.
+    ///
+    /// This operation is only valid for link annotations, and results in an
+    /// `Error::InvalidAnnotation` otherwise.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_highlight_mode(&mut self, mode: HighlightMode) -> Result<&mut Self, Error> {
+        let mode = types::highlight_mode_as_int(mode);
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_LinkAnnot_SetHighlightMode(self.handle, mode)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the icon used to display a text annotation.
+    ///
+    /// This operation is only valid for text annotations, and results in an
+    /// `Error::InvalidAnnotation` otherwise.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_icon(&mut self, icon: AnnotationIcon) -> Result<&mut Self, Error> {
+        let icon = types::annotation_icon_as_int(icon);
+        try!(self.doc.check_error(unsafe { haru::HPDF_TextAnnot_SetIcon(self.handle, icon) }));
+        Ok(self)
+    }
+
+    /// Sets whether the pop-up window of a text annotation is initially open.
+    ///
+    /// This operation is only valid for text annotations, and results in an
+    /// `Error::InvalidAnnotation` otherwise.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_opened(&mut self, opened: bool) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_TextAnnot_SetOpened(self.handle, opened as haru::HPDF_BOOL)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the color of the annotation, which is used for its icon, border or markup.
+    ///
+    /// Each floating-point value must be between `0.0` and `1.0`
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_rgb_color(&mut self, r: f32, g: f32, b: f32) -> Result<&mut Self, Error> {
+        let color = haru::HPDF_RGBColor { r: r, g: g, b: b };
+        try!(self.doc.check_error(unsafe { haru::HPDF_Annot_SetRGBColor(self.handle, color) }));
+        Ok(self)
+    }
+
+    /// Sets the opacity of the annotation, from `0.0` (fully transparent) to `1.0` (fully
+    /// opaque).
+    ///
+    /// An `Error::InvalidParameter` is returned if the opacity is out of range.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_opacity(&mut self, opacity: f32) -> Result<&mut Self, Error> {
+        if !(opacity >= 0.0 && opacity <= 1.0) {
+            return Err(Error::InvalidParameter);
+        }
+
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_MarkupAnnot_SetTransparency(self.handle, opacity)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the author of the annotation, typically displayed in the title bar of its pop-up
+    /// window.
+    ///
+    /// The author is encoded like the text of the annotation: with the document's current
+    /// encoding, or as UTF-8 if there is none.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_author(&mut self, author: &str) -> Result<&mut Self, Error> {
+        try!(self.set_text_entry(b"T\0", author));
+        Ok(self)
+    }
+
+    /// Sets a short description of the subject being addressed by the annotation.
+    ///
+    /// The subject is encoded like the text of the annotation: with the document's current
+    /// encoding, or as UTF-8 if there is none.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_subject(&mut self, subject: &str) -> Result<&mut Self, Error> {
+        try!(self.set_text_entry(b"Subj\0", subject));
+        Ok(self)
+    }
+
+    // `HPDF_MarkupAnnot_SetTitle` and `HPDF_MarkupAnnot_SetSubject` write their strings without an
+    // encoder, so the entries are written here instead, with the same encoder as the contents.
+    fn set_text_entry(&mut self, key: &[u8], text: &str) -> Result<(), Error> {
+        let encoder = try!(self.doc.text_encoder(None));
+        let text = try!(encoder::encode(&self.doc, encoder, text));
+        let value = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_String_New(haru::HPDF_GetMMgr(self.doc.0), text.text.as_ptr(), encoder)
+        }));
+        self.doc.check_error(unsafe {
+            haru::HPDF_Dict_Add(self.handle, key.as_ptr() as *const i8, value as *mut _)
+        })
+    }
+}
+
+/// Creates a new `Annotation` from a raw libharu annotation handle and its owner document.
+#[inline]
+pub fn new(annotation: haru::HPDF_Annotation, doc: Rc<DocumentHandle>) -> Annotation {
+    Annotation { handle: annotation, doc: doc }
+}
@@ -0,0 +1,109 @@
+use document::DocumentHandle;
+use error::Error;
+use haru;
+use std::rc::Rc;
+use types::{Point, Size};
+
+/// A position on a page that a viewer application jumps to, along with how that page is fit to
+/// the viewer's window.
+///
+/// A newly created destination displays its page using the mode set by `Destination::set_fit`.
+#[derive(Clone, Debug)]
+pub struct Destination {
+    handle: haru::HPDF_Destination,
+    // Keep a handle to the parent document to keep it from dropping while this `Destination` is
+    // in scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl Destination {
+    /// Displays the page with the point `(left, top)` positioned at the upper-left corner of the
+    /// window, magnified by the factor `zoom`.
+    ///
+    /// `left` and `top` must not be negative, and `zoom` must be between `0.08` and `32.0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_xyz(&mut self, left: f32, top: f32, zoom: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Destination_SetXYZ(self.handle, left, top, zoom)
+        }));
+        Ok(self)
+    }
+
+    /// Displays the page magnified to fit entirely within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit(&mut self) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Destination_SetFit(self.handle) }));
+        Ok(self)
+    }
+
+    /// Displays the page with the vertical coordinate `top` positioned at the top edge of the
+    /// window, magnified to fit the entire width of the page within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_h(&mut self, top: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Destination_SetFitH(self.handle, top) }));
+        Ok(self)
+    }
+
+    /// Displays the page with the horizontal coordinate `left` positioned at the left edge of the
+    /// window, magnified to fit the entire height of the page within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_v(&mut self, left: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Destination_SetFitV(self.handle, left) }));
+        Ok(self)
+    }
+
+    /// Displays the page magnified to fit the given rectangle entirely within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_r(&mut self, lower_left: Point, size: Size) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Destination_SetFitR(self.handle, lower_left.x, lower_left.y,
+                                           lower_left.x + size.width, lower_left.y + size.height)
+        }));
+        Ok(self)
+    }
+
+    /// Displays the page magnified to fit its bounding box entirely within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_b(&mut self) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Destination_SetFitB(self.handle) }));
+        Ok(self)
+    }
+
+    /// Displays the page with the vertical coordinate `top` positioned at the top edge of the
+    /// window, magnified to fit the entire width of its bounding box within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_bh(&mut self, top: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Destination_SetFitBH(self.handle, top) }));
+        Ok(self)
+    }
+
+    /// Displays the page with the horizontal coordinate `left` positioned at the left edge of the
+    /// window, magnified to fit the entire height of its bounding box within the window.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_fit_bv(&mut self, left: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Destination_SetFitBV(self.handle, left)
+        }));
+        Ok(self)
+    }
+}
+
+/// Creates a new `Destination` from a raw libharu destination handle and its owner document.
+#[inline]
+pub fn new(destination: haru::HPDF_Destination, doc: Rc<DocumentHandle>) -> Destination {
+    Destination { handle: destination, doc: doc }
+}
+
+/// Extracts the libharu handle from the given `Destination`.
+#[inline]
+pub fn get_handle(destination: &Destination) -> haru::HPDF_Destination {
+    destination.handle
+}
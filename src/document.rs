@@ -1,16 +1,27 @@
+use encoder::{self, CodeMaps};
 use error::{self, Error};
+use ext_gstate::{self, ExtGState};
 use font::{self, Font};
 use haru;
+use image::{self, Image};
+use outline::{self, Outline};
 use page::{self, Page};
+use std::ffi::{CStr, CString};
 use std::io::{Read, Seek, Write};
+use std::os::raw::c_char;
 use std::ptr;
 use std::rc::Rc;
 use stream;
-use types::{self, PageLayout};
+use types::{self, CjkEncoding, CjkFont, CjkLanguage, ColorSpace, CompressionMode, DateTime,
+            Encoding, EncryptionMode, InfoKey, PageLayout, PageNumberStyle, Permissions,
+            StandardFont, TtfOptions};
 
 /// A PDF document.
+#[derive(Debug)]
 pub struct Document {
     inner: Rc<DocumentHandle>,
+    // libharu offers no way to read back the compression mode, so it is tracked here.
+    compression_mode: CompressionMode,
 }
 
 impl Document {
@@ -21,9 +32,9 @@ impl Document {
             return Err(Error::AllocationFailed);
         }
 
-        let handle = DocumentHandle(handle_ptr);
+        let handle = DocumentHandle(handle_ptr, CodeMaps::default());
         try!(handle.check_error(unsafe { haru::HPDF_UseUTFEncodings(handle.0) }));
-        Ok(Document { inner: Rc::new(handle) })
+        Ok(Document { inner: Rc::new(handle), compression_mode: CompressionMode::none() })
     }
 
     /// Writes the PDF to the given `Write` stream, returning an error if the PDF could not be
@@ -57,6 +68,166 @@ impl Document {
         Ok(self)
     }
 
+    /// Returns the compression mode of this document.
+    ///
+    /// The default value is `CompressionMode::none()`.
+    pub fn compression_mode(&self) -> CompressionMode {
+        self.compression_mode
+    }
+
+    /// Sets which kinds of streams are compressed when the document is saved.
+    ///
+    /// An `Error::InvalidCompressionMode` is returned if libharu was built without zlib support.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_compression_mode(&mut self, mode: CompressionMode) -> Result<&mut Self, Error> {
+        let flags = types::compression_mode_as_int(mode);
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_SetCompressionMode(self.inner.0, flags)
+        }));
+        self.compression_mode = mode;
+        Ok(self)
+    }
+
+    /// Encrypts the document with the given owner and user passwords.
+    ///
+    /// Opening the document with the owner password grants every permission, while opening it
+    /// with the user password only grants the permissions set by `Document::set_permission`. An
+    /// empty user password allows the document to be opened without a password at all.
+    ///
+    /// An `Error::EncryptInvalidPassword` is returned if the owner password is empty or if both
+    /// passwords are the same.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_password(&mut self, owner: &str, user: &str) -> Result<&mut Self, Error> {
+        if owner.is_empty() || owner == user {
+            return Err(Error::EncryptInvalidPassword);
+        }
+
+        let owner = try!(CString::new(owner));
+        let user = try!(CString::new(user));
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_SetPassword(self.inner.0, owner.as_ptr(), user.as_ptr())
+        }));
+        Ok(self)
+    }
+
+    /// Sets the operations allowed when the document is opened with the user password.
+    ///
+    /// This operation will result in an error if `Document::set_password` has not yet been
+    /// called.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_permission(&mut self, permissions: Permissions) -> Result<&mut Self, Error> {
+        let permissions = types::permissions_as_int(permissions);
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_SetPermission(self.inner.0, permissions)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the encryption algorithm used to protect the document.
+    ///
+    /// The default value is `EncryptionMode::Rc4Key40`.
+    ///
+    /// This operation will result in an error if `Document::set_password` has not yet been
+    /// called.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_encryption_mode(&mut self, mode: EncryptionMode) -> Result<&mut Self, Error> {
+        let (mode, key_len) = types::encryption_mode_as_int(mode);
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_SetEncryptionMode(self.inner.0, mode, key_len)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the value of a textual entry of the document information dictionary, if it has been
+    /// set.
+    pub fn info(&self, key: InfoKey) -> Option<String> {
+        let encoder = unsafe { haru::HPDF_GetCurrentEncoder(self.inner.0) };
+        self.info_attr(types::info_key_as_int(key)).map(|value| encoder::decode(encoder, value))
+    }
+
+    /// Sets the value of a textual entry of the document information dictionary.
+    ///
+    /// The value is transcoded into the current encoding (see `Document::set_current_encoder`), so
+    /// an `Error::UnencodableCharacter` is returned if the encoding cannot represent it. Without a
+    /// current encoding, the value is encoded as UTF-8.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_info(&mut self, key: InfoKey, value: &str) -> Result<&mut Self, Error> {
+        // `haru::HPDF_SetInfoAttr` writes the value without an encoder if there is no current
+        // encoder, so the entry is written here instead, like outline titles.
+        let encoder = try!(self.inner.text_encoder(None));
+        let value = try!(encoder::encode(&self.inner, encoder, value));
+        let value = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_String_New(haru::HPDF_GetMMgr(self.inner.0), value.text.as_ptr(), encoder)
+        }));
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_Dict_Add((*self.inner.0).info,
+                                types::info_key_name(key).as_ptr() as *const i8,
+                                value as *mut _)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the current encoding, if one has been set.
+    pub fn current_encoder(&self) -> Option<Encoding> {
+        let encoder = unsafe { haru::HPDF_GetCurrentEncoder(self.inner.0) };
+        if encoder == ptr::null_mut() {
+            None
+        } else {
+            types::encoding_from_name(unsafe { encoder::name(encoder) })
+        }
+    }
+
+    /// Sets the current encoding, which is used to encode the textual entries of the document
+    /// information dictionary, and, unless another encoding is given, outline titles and
+    /// annotation texts. Without a current encoding, the latter are encoded as UTF-8.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_current_encoder(&mut self, encoding: Encoding) -> Result<&mut Self, Error> {
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_SetCurrentEncoder(self.inner.0,
+                                         types::encoding_name(encoding).as_ptr() as *const i8)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the date and time at which the document was created, if it has been set.
+    pub fn creation_date(&self) -> Option<DateTime> {
+        self.info_attr(haru::Enum__HPDF_InfoType::HPDF_INFO_CREATION_DATE)
+            .and_then(types::date_time_from_str)
+    }
+
+    /// Sets the date and time at which the document was created.
+    ///
+    /// An `Error::InvalidDateTime` is returned if any of the fields of `date` are out of range.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_creation_date(&mut self, date: DateTime) -> Result<&mut Self, Error> {
+        try!(self.set_info_date_attr(haru::Enum__HPDF_InfoType::HPDF_INFO_CREATION_DATE, date));
+        Ok(self)
+    }
+
+    /// Returns the date and time at which the document was most recently modified, if it has been
+    /// set.
+    pub fn modification_date(&self) -> Option<DateTime> {
+        self.info_attr(haru::Enum__HPDF_InfoType::HPDF_INFO_MOD_DATE)
+            .and_then(types::date_time_from_str)
+    }
+
+    /// Sets the date and time at which the document was most recently modified.
+    ///
+    /// An `Error::InvalidDateTime` is returned if any of the fields of `date` are out of range.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_modification_date(&mut self, date: DateTime) -> Result<&mut Self, Error> {
+        try!(self.set_info_date_attr(haru::Enum__HPDF_InfoType::HPDF_INFO_MOD_DATE, date));
+        Ok(self)
+    }
+
     /// Returns the page layout option for this document.
     pub fn page_layout(&self) -> PageLayout {
         types::page_layout_from_int(unsafe { haru::HPDF_GetPageLayout(self.inner.0) })
@@ -71,6 +242,30 @@ impl Document {
         Ok(self)
     }
 
+    /// Sets the labels displayed by viewer applications for the page at index `page_index`
+    /// (starting at `0`) and every following page, up to the next page given a label.
+    ///
+    /// Pages are numbered using `style`, starting at `first_page_number` and preceded by the
+    /// optional `prefix`. For example, `(4, PageNumberStyle::Decimal, 1, Some("A-"))` labels the
+    /// fifth page and onward as "A-1", "A-2", and so on.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn add_page_label(&mut self, page_index: u32, style: PageNumberStyle,
+                          first_page_number: u32,
+                          prefix: Option<&str>) -> Result<&mut Self, Error> {
+        let style = types::page_number_style_as_int(style);
+        let prefix = match prefix {
+            Some(prefix) => Some(try!(CString::new(prefix))),
+            None => None,
+        };
+
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_AddPageLabel(self.inner.0, page_index, style, first_page_number,
+                                    prefix.as_ref().map_or(ptr::null(), |prefix| prefix.as_ptr()))
+        }));
+        Ok(self)
+    }
+
     /// Creates a new page, adds it after the last page of a document, the returns it.
     pub fn add_page(&mut self) -> Result<Page, Error> {
         let handle = try!(self.inner.check_non_null_mut(unsafe {
@@ -86,20 +281,310 @@ impl Document {
         Ok(page::new(handle, self.inner.clone()))
     }
 
+    /// Creates a new entry in the document outline, then returns it.
+    ///
+    /// The entry is added as the last child of `parent`, or as a top-level entry if `parent` is
+    /// `None`. The title is encoded with the given encoding, or if `None`, with the current
+    /// encoding, or as UTF-8 if there is none.
+    pub fn create_outline(&mut self, parent: Option<&Outline>, title: &str,
+                          encoding: Option<Encoding>) -> Result<Outline, Error> {
+        let parent = parent.map_or(ptr::null_mut(), outline::get_handle);
+        let encoder = try!(self.inner.text_encoder(encoding));
+        let title = try!(encoder::encode(&self.inner, encoder, title));
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_CreateOutline(self.inner.0, parent, title.text.as_ptr(), encoder)
+        }));
+
+        Ok(outline::new(handle, self.inner.clone()))
+    }
+
+    /// Creates a new extended graphics state, then returns it.
+    pub fn create_ext_gstate(&mut self) -> Result<ExtGState, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_CreateExtGState(self.inner.0)
+        }));
+        Ok(ext_gstate::new(handle, self.inner.clone()))
+    }
+
+    /// Returns one of the standard fonts using the given encoding.
+    ///
+    /// Standard fonts are not embedded in the document. If no encoding is given,
+    /// `Encoding::Standard` is used, or the font's built-in encoding for `StandardFont::Symbol` and
+    /// `StandardFont::ZapfDingbats`. An `Error::InvalidEncoderType` is returned for
+    /// `Encoding::Utf8`, which only TrueType fonts support.
+    pub fn font(&mut self, font: StandardFont, encoding: Option<Encoding>) -> Result<Font, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_GetFont(self.inner.0, types::standard_font_name(font).as_ptr() as *const i8,
+                               encoding.map_or(ptr::null(), |e| {
+                                   types::encoding_name(e).as_ptr() as *const i8
+                               }))
+        }));
+
+        Ok(font::new(handle, self.inner.clone()))
+    }
+
+    /// Enables the built-in CID fonts and CMap encodings of the given language, making them
+    /// available to `cjk_font`.
+    ///
+    /// An `Error::DuplicateRegistration` is returned if the language has already been enabled.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn use_cjk(&mut self, language: CjkLanguage) -> Result<&mut Self, Error> {
+        try!(self.inner.check_error(unsafe {
+            match language {
+                CjkLanguage::Japanese => haru::HPDF_UseJPFonts(self.inner.0),
+                CjkLanguage::SimplifiedChinese => haru::HPDF_UseCNSFonts(self.inner.0),
+                CjkLanguage::TraditionalChinese => haru::HPDF_UseCNTFonts(self.inner.0),
+                CjkLanguage::Korean => haru::HPDF_UseKRFonts(self.inner.0),
+            }
+        }));
+        try!(self.inner.check_error(unsafe {
+            match language {
+                CjkLanguage::Japanese => haru::HPDF_UseJPEncodings(self.inner.0),
+                CjkLanguage::SimplifiedChinese => haru::HPDF_UseCNSEncodings(self.inner.0),
+                CjkLanguage::TraditionalChinese => haru::HPDF_UseCNTEncodings(self.inner.0),
+                CjkLanguage::Korean => haru::HPDF_UseKREncodings(self.inner.0),
+            }
+        }));
+        Ok(self)
+    }
+
+    /// Returns one of the built-in CID fonts using the given encoding. The fonts and encodings of
+    /// the relevant language must first be enabled with `use_cjk`.
+    ///
+    /// CID fonts are not embedded in the document.
+    pub fn cjk_font(&mut self, font: CjkFont, encoding: CjkEncoding) -> Result<Font, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_GetFont(self.inner.0, types::cjk_font_name(font).as_ptr() as *const i8,
+                               types::cjk_encoding_name(encoding).as_ptr() as *const i8)
+        }));
+
+        Ok(font::new(handle, self.inner.clone()))
+    }
+
+    /// Adds character codes (in the given encoding) that may not begin a line when word wrapping,
+    /// such as closing brackets and punctuation. The Japanese encodings come with a default set of
+    /// such codes.
+    ///
+    /// An encoding holds at most 128 codes; an `Error::JwwCodeNumberLimitExceeded` is returned,
+    /// and no code is added, if this limit would be exceeded. An `Error::InvalidParameter` is
+    /// returned if any code is `0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn add_line_head_codes(&mut self, encoding: CjkEncoding,
+                               codes: &[u16]) -> Result<&mut Self, Error> {
+        if codes.contains(&0) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let encoder = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_GetEncoder(self.inner.0,
+                                  types::cjk_encoding_name(encoding).as_ptr() as *const i8)
+        }));
+
+        // libharu adds codes until the list is full before failing, so the capacity is checked
+        // here to leave the list untouched on failure.
+        let line_head = unsafe {
+            &(*((*encoder).attr as haru::HPDF_CMapEncoderAttr)).jww_line_head
+        };
+        let mut used = line_head.iter().filter(|&&code| code != 0).count();
+        let mut new_codes = vec![];
+        for (i, &code) in codes.iter().enumerate() {
+            if line_head.contains(&code) || new_codes.contains(&code) {
+                continue;
+            }
+            if used == line_head.len() {
+                return Err(Error::JwwCodeNumberLimitExceeded(i as u64));
+            }
+            used += 1;
+            new_codes.push(code);
+        }
+
+        // libharu expects a zero-terminated list.
+        new_codes.push(0);
+        try!(self.inner.check_error(unsafe {
+            haru::HPDF_CMapEncoder_AddJWWLineHead(encoder, new_codes.as_ptr())
+        }));
+        Ok(self)
+    }
+
     /// Reads and loads a TTF font from the given stream.
-    pub fn load_ttf_font<R: Read + Seek>(&mut self, r: R) -> Result<Font, Error> {
+    pub fn load_ttf_font<R: Read + Seek>(&mut self, r: R,
+                                         options: TtfOptions) -> Result<Font, Error> {
         let name = try!(self.inner.check_non_null(unsafe {
             let stream = stream::convert_read_stream(&*self.inner, r);
             // `haru::HPDF_LoadTTFontFromStream` consumes the stream.
-            haru::HPDF_LoadTTFontFromStream(self.inner.0, stream, 1, ptr::null())
+            haru::HPDF_LoadTTFontFromStream(self.inner.0, stream, options.embed as haru::HPDF_BOOL,
+                                            ptr::null())
+        }));
+
+        self.loaded_font(name, options.encoding)
+    }
+
+    /// Reads and loads the font at the given zero-based index of a TrueType collection (TTC) from
+    /// the given stream.
+    ///
+    /// An `Error::InvalidTtcFile` is returned if the stream does not contain a TrueType collection,
+    /// and an `Error::InvalidTtcIndex` is returned if the collection contains no font at `index`.
+    pub fn load_ttc_font<R: Read + Seek>(&mut self, r: R, index: u32,
+                                         options: TtfOptions) -> Result<Font, Error> {
+        let name = try!(self.inner.check_non_null(unsafe {
+            let stream = stream::convert_read_stream(&*self.inner, r);
+            // `haru::HPDF_LoadTTFontFromStream2` consumes the stream.
+            haru::HPDF_LoadTTFontFromStream2(self.inner.0, stream, index,
+                                             options.embed as haru::HPDF_BOOL, ptr::null())
+        }));
+
+        self.loaded_font(name, options.encoding)
+    }
+
+    /// Reads and loads a Type 1 font from the given AFM (font metrics) stream and, optionally, the
+    /// PFB or PFA (font program) stream. The font is embedded in the document only if the font
+    /// program is given.
+    ///
+    /// An `Error::InvalidAfmHeader` or `Error::InvalidCharMatricsData` is returned if the AFM data
+    /// is malformed, and an `Error::UnsupportedType1Font` is returned if the font program cannot
+    /// be read.
+    pub fn load_type1_font<A, P>(&mut self, afm: A, pfb: Option<P>) -> Result<Font, Error>
+        where A: Read + Seek, P: Read + Seek
+    {
+        let name = try!(self.inner.check_non_null(unsafe {
+            let afm = stream::convert_read_stream(&*self.inner, afm);
+            let pfb = match pfb {
+                Some(pfb) => stream::convert_read_stream(&*self.inner, pfb),
+                None => ptr::null_mut(),
+            };
+            // `haru::HPDF_LoadType1FontFromStream` consumes both streams.
+            haru::HPDF_LoadType1FontFromStream(self.inner.0, afm, pfb)
         }));
 
         let handle = try!(self.inner.check_non_null_mut(unsafe {
-            haru::HPDF_GetFont(self.inner.0, name, b"UTF-8".as_ptr() as *const i8)
+            haru::HPDF_GetFont(self.inner.0, name, ptr::null())
         }));
 
         Ok(font::new(handle, self.inner.clone()))
     }
+
+    /// Returns the loaded font with the given name using the given encoding.
+    fn loaded_font(&mut self, name: *const c_char, encoding: Encoding) -> Result<Font, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_GetFont(self.inner.0, name,
+                               types::encoding_name(encoding).as_ptr() as *const i8)
+        }));
+
+        Ok(font::new(handle, self.inner.clone()))
+    }
+
+    /// Reads and loads a PNG image from the given stream.
+    pub fn load_png_image<R: Read + Seek>(&mut self, r: R) -> Result<Image, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            let stream = stream::convert_read_stream(&*self.inner, r);
+            // `haru::HPDF_LoadPngImageFromStream` consumes the stream.
+            haru::HPDF_LoadPngImageFromStream(self.inner.0, stream)
+        }));
+
+        Ok(image::new(handle, self.inner.clone()))
+    }
+
+    /// Reads and loads a JPEG image from the given stream.
+    pub fn load_jpeg_image<R: Read + Seek>(&mut self, r: R) -> Result<Image, Error> {
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            let stream = stream::convert_read_stream(&*self.inner, r);
+            // `haru::HPDF_LoadJpegImageFromStream` consumes the stream.
+            haru::HPDF_LoadJpegImageFromStream(self.inner.0, stream)
+        }));
+
+        Ok(image::new(handle, self.inner.clone()))
+    }
+
+    /// Loads an image from a buffer of raw, uncompressed pixel data.
+    ///
+    /// Pixels are laid out row by row, starting from the top-left corner, with each color
+    /// component packed into `bits_per_component` bits. Each row starts on a byte boundary, so a
+    /// row takes up `ceil(width * components * bits_per_component / 8)` bytes, where `components`
+    /// is 1 for gray, 3 for RGB and 4 for CMYK.
+    ///
+    /// Only `ColorSpace::DeviceGray`, `ColorSpace::DeviceRgb` and `ColorSpace::DeviceCmyk` are
+    /// supported; any other color space results in `Error::InvalidColorSpace`. An
+    /// `Error::InvalidImage` is returned if either dimension is zero, if `bits_per_component` is
+    /// not one of 1, 2, 4 or 8, or if `data` is shorter than `height` rows.
+    pub fn load_raw_image(&mut self, data: &[u8], width: u32, height: u32, color_space: ColorSpace,
+                          bits_per_component: u32) -> Result<Image, Error> {
+        let components = match color_space {
+            ColorSpace::DeviceGray => 1,
+            ColorSpace::DeviceRgb => 3,
+            ColorSpace::DeviceCmyk => 4,
+            _ => return Err(Error::InvalidColorSpace),
+        };
+
+        match bits_per_component {
+            1 | 2 | 4 | 8 => (),
+            _ => return Err(Error::InvalidImage),
+        }
+
+        let (width64, height64, bpc64) = (width as u64, height as u64, bits_per_component as u64);
+        let row_bits = width64.checked_mul(components).and_then(|n| n.checked_mul(bpc64));
+        let len = row_bits.and_then(|bits| ((bits + 7) / 8).checked_mul(height64));
+        // libharu ignores row padding and copies `ceil(width * height * bpc / 8)` bytes for every
+        // component, which may be more or fewer bytes than the padded image occupies.
+        let haru_bits = width64.checked_mul(height64).and_then(|n| n.checked_mul(bpc64));
+        let haru_len = haru_bits.and_then(|bits| ((bits + 7) / 8).checked_mul(components));
+        let (len, haru_len) = match (len, haru_len) {
+            (Some(len), Some(haru_len)) if width != 0 && height != 0 &&
+                                           data.len() as u64 >= len => {
+                (len as usize, haru_len as usize)
+            }
+            _ => return Err(Error::InvalidImage),
+        };
+
+        // Keep libharu from reading past the end of `data`.
+        let mut padded;
+        let buf = if haru_len > data.len() {
+            padded = data.to_vec();
+            padded.resize(haru_len, 0);
+            &padded[..]
+        } else {
+            data
+        };
+
+        let handle = try!(self.inner.check_non_null_mut(unsafe {
+            haru::HPDF_LoadRawImageFromMem(self.inner.0, buf.as_ptr(), width, height,
+                                           types::color_space_as_int(color_space),
+                                           bits_per_component)
+        }));
+
+        // Complete the image stream with the rows libharu left out.
+        if haru_len < len {
+            try!(self.inner.check_error(unsafe {
+                haru::HPDF_Stream_Write((*handle).stream, data[haru_len..].as_ptr(),
+                                        (len - haru_len) as haru::HPDF_UINT)
+            }));
+        }
+
+        Ok(image::new(handle, self.inner.clone()))
+    }
+
+    /// Returns the raw bytes of an entry of the document information dictionary, if it has been
+    /// set.
+    fn info_attr(&self, key: haru::HPDF_InfoType) -> Option<&[u8]> {
+        let value = unsafe { haru::HPDF_GetInfoAttr(self.inner.0, key) };
+        if value == ptr::null() {
+            // A missing entry is not an error, but clear any error libharu might have recorded.
+            unsafe { haru::HPDF_ResetError(self.inner.0); }
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(value) }.to_bytes())
+        }
+    }
+
+    /// Sets a date entry of the document information dictionary.
+    fn set_info_date_attr(&mut self, key: haru::HPDF_InfoType,
+                          date: DateTime) -> Result<(), Error> {
+        try!(date.validate());
+        self.inner.check_error(unsafe {
+            haru::HPDF_SetInfoDateAttr(self.inner.0, key, types::date_time_as_raw(date))
+        })
+    }
 }
 
 /// A wrapper around a raw libharu handle for a document.
@@ -114,7 +599,11 @@ impl Document {
 /// corresponding document and its child objects will fail indiscriminately. It is best to run all
 /// possible errors (status values, null pointer return values, etc.) through `DocumentHandle`'s
 /// error handling methods.
-pub struct DocumentHandle(pub haru::HPDF_Doc);
+///
+/// The handle also caches the character-to-code maps used to transcode text for the document's
+/// encoders.
+#[derive(Debug)]
+pub struct DocumentHandle(pub haru::HPDF_Doc, CodeMaps);
 
 impl DocumentHandle {
     /// Returns an `Error` if the given status is not a successful code.
@@ -151,6 +640,31 @@ impl DocumentHandle {
     pub fn check_non_null_mut<T>(&self, p: *mut T) -> Result<*mut T, Error> {
         self.check_non_null(p as *const _).map(|p| p as *mut _)
     }
+
+    /// Returns the encoder for text strings such as outline titles and annotation contents: the
+    /// encoder of the given encoding, or else the current encoder (see
+    /// `Document::set_current_encoder`), or else the UTF-8 encoder.
+    pub fn text_encoder(&self, encoding: Option<Encoding>) -> Result<haru::HPDF_Encoder, Error> {
+        let name = match encoding {
+            Some(encoding) => types::encoding_name(encoding),
+            None => {
+                let encoder = unsafe { haru::HPDF_GetCurrentEncoder(self.0) };
+                if encoder != ptr::null_mut() {
+                    return Ok(encoder);
+                }
+                types::encoding_name(Encoding::Utf8)
+            }
+        };
+
+        self.check_non_null_mut(unsafe {
+            haru::HPDF_GetEncoder(self.0, name.as_ptr() as *const i8)
+        })
+    }
+
+    /// Returns the character-to-code maps of the document's encoders.
+    pub fn code_maps(&self) -> &CodeMaps {
+        &self.1
+    }
 }
 
 impl Drop for DocumentHandle {
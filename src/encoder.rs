@@ -0,0 +1,192 @@
+use document::DocumentHandle;
+use error::Error;
+use haru;
+use std::cell::RefCell;
+use std::char;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::rc::Rc;
+
+/// Text transcoded for a libharu encoder, ready to be passed to libharu.
+pub struct EncodedText {
+    /// The transcoded, NUL-terminated text.
+    pub text: CString,
+    // For each byte offset into `text` (including its end), the byte offset of the corresponding
+    // character in the source string. `None` if both offsets are always identical.
+    offsets: Option<Vec<usize>>,
+}
+
+impl EncodedText {
+    /// Converts a byte offset into the transcoded text to the byte offset of the same character in
+    /// the source string. Offsets inside a multi-byte character are rounded down to its start.
+    pub fn source_offset(&self, offset: usize) -> usize {
+        match self.offsets {
+            Some(ref offsets) => offsets[offset],
+            None => offset,
+        }
+    }
+}
+
+/// The character-to-code maps of a document's encoders, built when an encoder is first used for
+/// transcoding. libharu only maps codes to characters, so building a map means querying every
+/// code of the encoder, which is too slow to repeat for every piece of text.
+#[derive(Debug, Default)]
+pub struct CodeMaps(RefCell<HashMap<usize, Rc<HashMap<char, u16>>>>);
+
+impl CodeMaps {
+    /// Returns the character-to-code map of the given encoder, mapping each character to the
+    /// lowest code that the encoder maps to it, and each unmapped ASCII control character to its
+    /// own code.
+    ///
+    /// Returns `None` if the encoder is neither a single-byte nor a multi-byte encoder.
+    fn get(&self, encoder: haru::HPDF_Encoder) -> Option<Rc<HashMap<char, u16>>> {
+        use haru::Enum__HPDF_EncoderType::*;
+
+        if let Some(codes) = self.0.borrow().get(&(encoder as usize)) {
+            return Some(codes.clone());
+        }
+
+        let max_code = match unsafe { haru::HPDF_Encoder_GetType(encoder) } {
+            HPDF_ENCODER_TYPE_SINGLE_BYTE => 0xff,
+            HPDF_ENCODER_TYPE_DOUBLE_BYTE => 0xffff,
+            _ => return None,
+        };
+
+        let mut codes = HashMap::new();
+        for code in 1..max_code + 1 {
+            let unicode = unsafe {
+                haru::HPDF_Encoder_GetUnicode(encoder, code as haru::HPDF_UINT16)
+            };
+            if let Some(c) = char::from_u32(unicode as u32) {
+                if c != '\0' {
+                    codes.entry(c).or_insert(code as u16);
+                }
+            }
+        }
+
+        // libharu leaves the ASCII control characters unmapped, but line feeds and tabs must still
+        // reach its text layout, so they are passed through as their own byte.
+        for code in 1..0x20 {
+            codes.entry(char::from(code as u8)).or_insert(code);
+        }
+
+        let codes = Rc::new(codes);
+        self.0.borrow_mut().insert(encoder as usize, codes.clone());
+        Some(codes)
+    }
+}
+
+/// Transcodes the given text for the given encoder of the given document.
+///
+/// The text is passed through byte for byte if the encoder is null, the UTF-8 encoder, or a
+/// `FontSpecific` encoder (whose codes select glyphs directly). Otherwise, each character is
+/// replaced with the code that the encoder maps to it (ASCII control characters are kept as they
+/// are), and an `Error::UnencodableCharacter` is returned if there is no such code.
+pub fn encode(doc: &DocumentHandle, encoder: haru::HPDF_Encoder,
+              text: &str) -> Result<EncodedText, Error> {
+    if is_passthrough(encoder) {
+        return Ok(EncodedText { text: try!(CString::new(text)), offsets: None });
+    }
+
+    let codes = match doc.code_maps().get(encoder) {
+        Some(codes) => codes,
+        None => return Err(Error::InvalidEncoderType),
+    };
+
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (i, c) in text.char_indices() {
+        if c == '\0' {
+            return Err(Error::StringWithInternalNul);
+        }
+
+        let code = match codes.get(&c) {
+            Some(&code) => code,
+            None => return Err(Error::UnencodableCharacter(c)),
+        };
+
+        // Multi-byte codes are written most significant byte first.
+        if code > 0xff {
+            bytes.push((code >> 8) as u8);
+            offsets.push(i);
+        }
+        bytes.push(code as u8);
+        offsets.push(i);
+    }
+    offsets.push(text.len());
+
+    Ok(EncodedText { text: try!(CString::new(bytes)), offsets: Some(offsets) })
+}
+
+/// Returns the distinct characters of the given text, in order of first appearance, that the given
+/// encoder of the given document cannot represent.
+///
+/// Text passed through a `FontSpecific` encoder selects one glyph per byte, so every non-ASCII
+/// character is considered unencodable there.
+pub fn unencodable_chars(doc: &DocumentHandle, encoder: haru::HPDF_Encoder,
+                         text: &str) -> Vec<char> {
+    let font_specific = encoder != ptr::null_mut() &&
+                        unsafe { name(encoder) } == b"FontSpecific";
+    if is_passthrough(encoder) && !font_specific {
+        return vec![];
+    }
+
+    let codes = if font_specific { None } else { doc.code_maps().get(encoder) };
+
+    let mut seen = HashSet::new();
+    let mut missing = vec![];
+    for c in text.chars() {
+        if !seen.insert(c) {
+            continue;
+        }
+
+        let encodable = match codes {
+            Some(ref codes) => codes.contains_key(&c),
+            None => font_specific && (c as u32) < 0x80,
+        };
+        if !encodable {
+            missing.push(c);
+        }
+    }
+    missing
+}
+
+/// Decodes text written by the given encoder into a `String`.
+///
+/// Text is decoded as UTF-8 (replacing invalid sequences) unless the encoder is a single-byte
+/// encoder.
+pub fn decode(encoder: haru::HPDF_Encoder, text: &[u8]) -> String {
+    use haru::Enum__HPDF_EncoderType::*;
+
+    if encoder == ptr::null_mut() {
+        return String::from_utf8_lossy(text).into_owned();
+    }
+
+    match unsafe { haru::HPDF_Encoder_GetType(encoder) } {
+        HPDF_ENCODER_TYPE_SINGLE_BYTE => (),
+        _ => return String::from_utf8_lossy(text).into_owned(),
+    }
+
+    text.iter().map(|&b| {
+        let unicode = unsafe { haru::HPDF_Encoder_GetUnicode(encoder, b as haru::HPDF_UINT16) };
+        char::from_u32(unicode as u32).unwrap_or('\u{fffd}')
+    }).collect()
+}
+
+/// Returns the name of the given encoder.
+///
+/// # Safety
+///
+/// `encoder` must be a valid, non-null encoder, and the returned slice must not outlive it.
+pub unsafe fn name<'a>(encoder: haru::HPDF_Encoder) -> &'a [u8] {
+    CStr::from_ptr((*encoder).name.as_ptr()).to_bytes()
+}
+
+/// Returns whether text is passed to the given encoder without transcoding.
+fn is_passthrough(encoder: haru::HPDF_Encoder) -> bool {
+    encoder == ptr::null_mut() || match unsafe { name(encoder) } {
+        b"UTF-8" | b"FontSpecific" => true,
+        _ => false,
+    }
+}
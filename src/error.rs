@@ -31,7 +31,7 @@ pub enum Error {
     DuplicateRegistration,
     /// Cannot register a character to the Japanese word wrap characters list.
     JwwCodeNumberLimitExceeded(u64),
-    /// * Tried to set the owner password to NULL.
+    /// * Tried to set the owner password to NULL or an empty string.
     /// * Owner and user password are the same.
     EncryptInvalidPassword,
     /// Internal error. Data consistency was lost.
@@ -57,8 +57,7 @@ pub enum Error {
     InvalidBitPerComponent,
     /// Cannot recognize char-matrics-data of AFM file.
     InvalidCharMatricsData,
-    /// * Invalid color_space parameter of `Document::load_raw_image_from_file` or
-    ///   `Document::load_raw_image_from_mem`.
+    /// * Invalid color_space parameter of `Document::load_raw_image`.
     /// * Color-space of a image which was set as mask-image is invalid.
     /// * Invoked function invalid in present color-space.
     InvalidColorSpace,
@@ -87,7 +86,8 @@ pub enum Error {
     InvalidFontdefType,
     /// Font with the specified name is not found.
     InvalidFontName,
-    /// Unsupported image format.
+    /// * Unsupported image format.
+    /// * Invalid dimensions, bit depth or data length passed to `Document::load_raw_image`.
     InvalidImage,
     /// Unsupported image format.
     InvalidJpegData,
@@ -236,6 +236,9 @@ pub enum Error {
     /// The provided string contained a 0 (NUL) byte, so it could not be converted into a C string
     /// consumable by libharu.
     StringWithInternalNul,
+    /// The provided string contained a character that cannot be represented in the encoding of the
+    /// font or document it was used with.
+    UnencodableCharacter(char),
 }
 
 impl From<NulError> for Error {
@@ -385,4 +388,5 @@ fn error_translation() {
     assert!(from(0, 0).is_ok());
     expect_error!(from(0x1075, 0), Error::InvalidFont);
     expect_error!(from(0x1060, 9), Error::TtfMissingTable(9));
+    expect_error!(from(0x1021, 0), Error::InvalidCompressionMode);
 }
@@ -0,0 +1,70 @@
+use document::DocumentHandle;
+use error::Error;
+use haru;
+use std::rc::Rc;
+use types::{self, BlendMode};
+
+/// An extended graphics state, which holds graphics parameters (such as transparency) that can be
+/// applied to a page with `Page::set_ext_gstate`.
+///
+/// An extended graphics state becomes read-only once it has been applied to a page. Any attempt to
+/// modify it afterward results in an `Error::ExtGstateReadOnly`.
+#[derive(Clone, Debug)]
+pub struct ExtGState {
+    handle: haru::HPDF_ExtGState,
+    // Keep a handle to the parent document to keep it from dropping while this `ExtGState` is in
+    // scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl ExtGState {
+    /// Sets the opacity used when stroking paths, from `0.0` (fully transparent) to `1.0` (fully
+    /// opaque).
+    ///
+    /// An `Error::ExtGstateOutOfRange` is returned if the opacity is out of range.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_alpha_stroke(&mut self, alpha: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_ExtGState_SetAlphaStroke(self.handle, alpha)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the opacity used when filling paths and drawing images, from `0.0` (fully
+    /// transparent) to `1.0` (fully opaque).
+    ///
+    /// An `Error::ExtGstateOutOfRange` is returned if the opacity is out of range.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_alpha_fill(&mut self, alpha: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_ExtGState_SetAlphaFill(self.handle, alpha)
+        }));
+        Ok(self)
+    }
+
+    /// Sets how colors being drawn are combined with the colors already on the page.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_blend_mode(&mut self, mode: BlendMode) -> Result<&mut Self, Error> {
+        let mode = types::blend_mode_as_int(mode);
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_ExtGState_SetBlendMode(self.handle, mode)
+        }));
+        Ok(self)
+    }
+}
+
+/// Creates a new `ExtGState` from a raw libharu extended graphics state handle and its owner
+/// document.
+#[inline]
+pub fn new(ext_gstate: haru::HPDF_ExtGState, doc: Rc<DocumentHandle>) -> ExtGState {
+    ExtGState { handle: ext_gstate, doc: doc }
+}
+
+/// Extracts the libharu handle from the given `ExtGState`.
+#[inline]
+pub fn get_handle(ext_gstate: &ExtGState) -> haru::HPDF_ExtGState {
+    ext_gstate.handle
+}
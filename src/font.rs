@@ -1,11 +1,152 @@
 use document::DocumentHandle;
+use encoder::{self, EncodedText};
+use error::Error;
 use haru;
+use std::collections::HashSet;
+use std::ffi::CStr;
 use std::rc::Rc;
+use types::{self, Encoding, Point, TextWidth};
 
-#[derive(Clone)]
+/// A font loaded into a document, ready to be used for drawing text on any of its pages.
+///
+/// Unless stated otherwise, metrics are given in glyph space units, which are 1/1000 of the font
+/// size.
+#[derive(Clone, Debug)]
 pub struct Font {
     handle: haru::HPDF_Font,
-    doc: Rc<DocumentHandle>
+    // Keep a handle to the parent document to keep it from dropping while this `Font` is in scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl Font {
+    /// Returns the name of the font.
+    pub fn name(&self) -> String {
+        unsafe { CStr::from_ptr(haru::HPDF_Font_GetFontName(self.handle)) }
+            .to_string_lossy().into_owned()
+    }
+
+    /// Returns the name of the encoding used by the font.
+    pub fn encoding_name(&self) -> String {
+        unsafe { CStr::from_ptr(haru::HPDF_Font_GetEncodingName(self.handle)) }
+            .to_string_lossy().into_owned()
+    }
+
+    /// Returns the encoding used by the font, if it is one of the built-in `Encoding`s.
+    pub fn encoding(&self) -> Option<Encoding> {
+        types::encoding_from_name(unsafe {
+            CStr::from_ptr(haru::HPDF_Font_GetEncodingName(self.handle))
+        }.to_bytes())
+    }
+
+    /// Returns the distance from the baseline to the top of the tallest glyph.
+    pub fn ascent(&self) -> i32 {
+        unsafe { haru::HPDF_Font_GetAscent(self.handle) }
+    }
+
+    /// Returns the distance from the baseline to the bottom of the lowest glyph. This value is
+    /// usually negative.
+    pub fn descent(&self) -> i32 {
+        unsafe { haru::HPDF_Font_GetDescent(self.handle) }
+    }
+
+    /// Returns the distance from the baseline to the top of flat lowercase letters (e.g. "x").
+    pub fn x_height(&self) -> u32 {
+        unsafe { haru::HPDF_Font_GetXHeight(self.handle) }
+    }
+
+    /// Returns the distance from the baseline to the top of flat uppercase letters (e.g. "H").
+    pub fn cap_height(&self) -> u32 {
+        unsafe { haru::HPDF_Font_GetCapHeight(self.handle) }
+    }
+
+    /// Returns the bounding box that would contain every glyph of the font if they were drawn on
+    /// top of each other, as its `(lower_left, upper_right)` corners.
+    pub fn bbox(&self) -> (Point, Point) {
+        let bbox = unsafe { haru::HPDF_Font_GetBBox(self.handle) };
+        (Point::new(bbox.left, bbox.bottom), Point::new(bbox.right, bbox.top))
+    }
+
+    /// Returns the advance width of the glyph for the given character.
+    ///
+    /// Returns `0` if the font has no glyph for the character, or if the character lies outside
+    /// of the Basic Multilingual Plane.
+    pub fn unicode_width(&self, c: char) -> i32 {
+        if c as u32 > 0xffff {
+            return 0;
+        }
+
+        unsafe { haru::HPDF_Font_GetUnicodeWidth(self.handle, c as haru::HPDF_UNICODE) }
+    }
+
+    /// Returns whether the font can draw the given character, i.e. whether the font's encoding can
+    /// represent it and, for TrueType fonts, whether the font contains a glyph for it.
+    ///
+    /// Characters for which this returns `false` are drawn as a placeholder glyph (usually an
+    /// empty box), if at all.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.missing_glyphs(&c.to_string()).is_empty()
+    }
+
+    /// Returns the distinct characters of the given text, in order of first appearance, that the
+    /// font cannot draw (see `Font::has_glyph`). Whitespace and control characters are checked
+    /// like any other character.
+    pub fn missing_glyphs(&self, text: &str) -> Vec<char> {
+        use haru::Enum__HPDF_FontDefType::*;
+
+        let attr = self.attr();
+        let unencodable: HashSet<char> =
+            encoder::unencodable_chars(&self.doc, attr.encoder, text).into_iter().collect();
+
+        // libharu only reads the Basic Multilingual Plane from TrueType character maps, and a
+        // format 0 map only covers the first 256 characters (larger ones would wrap around).
+        let max_glyph_char = match unsafe { (*attr.fontdef).type_ } {
+            HPDF_FONTDEF_TYPE_TRUETYPE => {
+                let ttf_attr = unsafe { (*attr.fontdef).attr as haru::HPDF_TTFontDefAttr };
+                match unsafe { (*ttf_attr).cmap.format } {
+                    0 => Some(0xff),
+                    _ => Some(0xffff),
+                }
+            }
+            _ => None,
+        };
+
+        let mut seen = HashSet::new();
+        let mut missing = vec![];
+        for c in text.chars() {
+            if !seen.insert(c) {
+                continue;
+            }
+
+            let has_glyph = match max_glyph_char {
+                Some(max) => c as u32 <= max && unsafe {
+                    haru::HPDF_TTFontDef_GetGlyphid(attr.fontdef, c as haru::HPDF_UINT16)
+                } != 0,
+                None => true,
+            };
+            if unencodable.contains(&c) || !has_glyph {
+                missing.push(c);
+            }
+        }
+        missing
+    }
+
+    /// Measures the given text as if it were drawn with this font, without any character spacing,
+    /// word spacing or scaling applied.
+    ///
+    /// An `Error::UnencodableCharacter` is returned if the font's encoding cannot represent the
+    /// text.
+    pub fn text_width(&self, text: &str) -> Result<TextWidth, Error> {
+        let text = try!(encode(self, text));
+        let bytes = text.text.as_bytes();
+        Ok(types::text_width_from_raw(unsafe {
+            haru::HPDF_Font_TextWidth(self.handle, bytes.as_ptr(), bytes.len() as haru::HPDF_UINT)
+        }))
+    }
+
+    /// Returns the internal attributes of the font.
+    fn attr(&self) -> &haru::HPDF_FontAttr_Rec {
+        unsafe { &*((*self.handle).attr as haru::HPDF_FontAttr) }
+    }
 }
 
 /// Creates a new `Font` from a raw libharu font handle and its owner document.
@@ -19,3 +160,8 @@ pub fn new(font: haru::HPDF_Font, doc: Rc<DocumentHandle>) -> Font {
 pub fn get_handle(font: &Font) -> haru::HPDF_Font {
     font.handle
 }
+
+/// Transcodes the given text into the encoding used by the given `Font`.
+pub fn encode(font: &Font, text: &str) -> Result<EncodedText, Error> {
+    encoder::encode(&font.doc, font.attr().encoder, text)
+}
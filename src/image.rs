@@ -0,0 +1,58 @@
+use document::DocumentHandle;
+use error::Error;
+use haru;
+use std::ffi::CStr;
+use std::rc::Rc;
+use types::{self, ColorSpace, Size};
+
+/// An image loaded into a document, ready to be drawn on any of its pages.
+#[derive(Clone, Debug)]
+pub struct Image {
+    handle: haru::HPDF_Image,
+    // Keep a handle to the parent document to keep it from dropping while this `Image` is in scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl Image {
+    /// Returns the size of the image in pixels.
+    pub fn size(&self) -> Size {
+        Size::new(self.width() as f32, self.height() as f32)
+    }
+
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        unsafe { haru::HPDF_Image_GetWidth(self.handle) }
+    }
+
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        unsafe { haru::HPDF_Image_GetHeight(self.handle) }
+    }
+
+    /// Returns the number of bits used to describe each color component of a pixel.
+    pub fn bits_per_component(&self) -> u32 {
+        unsafe { haru::HPDF_Image_GetBitsPerComponent(self.handle) }
+    }
+
+    /// Returns the color space of the image.
+    ///
+    /// An error is returned if libharu cannot determine the color space of the image.
+    pub fn color_space(&self) -> Result<ColorSpace, Error> {
+        let name = try!(self.doc.check_non_null(unsafe {
+            haru::HPDF_Image_GetColorSpace(self.handle)
+        }));
+        Ok(types::color_space_from_name(unsafe { CStr::from_ptr(name) }.to_bytes()))
+    }
+}
+
+/// Creates a new `Image` from a raw libharu image handle and its owner document.
+#[inline]
+pub fn new(image: haru::HPDF_Image, doc: Rc<DocumentHandle>) -> Image {
+    Image { handle: image, doc: doc }
+}
+
+/// Extracts the libharu handle from the given `Image`.
+#[inline]
+pub fn get_handle(image: &Image) -> haru::HPDF_Image {
+    image.handle
+}
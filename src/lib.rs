@@ -1,14 +1,29 @@
 extern crate libharu_sys as haru;
 
+mod annotation;
+mod destination;
 mod document;
+mod encoder;
 mod error;
+mod ext_gstate;
 mod font;
+mod image;
+mod outline;
 mod page;
 mod stream;
 mod types;
 
+pub use annotation::Annotation;
+pub use destination::Destination;
 pub use document::Document;
 pub use error::Error;
+pub use ext_gstate::ExtGState;
 pub use font::Font;
+pub use image::Image;
+pub use outline::Outline;
 pub use page::Page;
-pub use types::{ColorSpace, LineCap, LineJoin, PageLayout, Point, Size, TextAlignment};
+pub use types::{AnnotationIcon, BlendMode, BorderStyle, CjkEncoding, CjkFont, CjkLanguage,
+                ColorSpace, CompressionMode, DateTime, Encoding, EncryptionMode, HighlightMode,
+                InfoKey, LineCap, LineJoin, MarkupKind, Matrix, Orientation, PageLayout,
+                PageNumberStyle, PaperSize, Permissions, Point, Size, StandardFont, TextAlignment,
+                TextRectOutcome, TextRenderingMode, TextWidth, TtfOptions};
@@ -0,0 +1,49 @@
+use destination::{self, Destination};
+use document::DocumentHandle;
+use error::Error;
+use haru;
+use std::rc::Rc;
+
+/// An entry of the document outline (also known as a bookmark), which viewer applications display
+/// as a navigable tree.
+#[derive(Clone, Debug)]
+pub struct Outline {
+    handle: haru::HPDF_Outline,
+    // Keep a handle to the parent document to keep it from dropping while this `Outline` is in
+    // scope.
+    doc: Rc<DocumentHandle>,
+}
+
+impl Outline {
+    /// Sets whether the children of this entry are initially expanded.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_opened(&mut self, opened: bool) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Outline_SetOpened(self.handle, opened as haru::HPDF_BOOL)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the destination to jump to when this entry is clicked.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_destination(&mut self, destination: &Destination) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Outline_SetDestination(self.handle, destination::get_handle(destination))
+        }));
+        Ok(self)
+    }
+}
+
+/// Creates a new `Outline` from a raw libharu outline handle and its owner document.
+#[inline]
+pub fn new(outline: haru::HPDF_Outline, doc: Rc<DocumentHandle>) -> Outline {
+    Outline { handle: outline, doc: doc }
+}
+
+/// Extracts the libharu handle from the given `Outline`.
+#[inline]
+pub fn get_handle(outline: &Outline) -> haru::HPDF_Outline {
+    outline.handle
+}
@@ -1,13 +1,23 @@
+use annotation::{self, Annotation};
+use destination::{self, Destination};
 use document::DocumentHandle;
+use encoder::{self, EncodedText};
 use error::Error;
+use ext_gstate::{self, ExtGState};
 use font::{self, Font};
 use haru;
+use image::{self, Image};
 use std::ffi::CString;
 use std::ptr;
 use std::rc::Rc;
-use types::{self, ColorSpace, LineCap, LineJoin, Point, Size, TextAlignment};
+use types::{self, ColorSpace, LineCap, LineJoin, MarkupKind, Matrix, Orientation, PaperSize, Point,
+            Size, TextAlignment, TextRectOutcome, TextRenderingMode};
+
+/// The maximum depth of the graphics state stack supported by libharu.
+const MAX_GSTATE_DEPTH: u32 = 28;
 
 /// A single page of a PDF document.
+#[derive(Debug)]
 pub struct Page {
     handle: haru::HPDF_Page,
     // Keep a handle to the parent document to keep it from dropping while this `Page` is in scope.
@@ -45,6 +55,30 @@ impl Page {
         Ok(self)
     }
 
+    /// Sets the page width and height to those of a standard paper size in the given orientation.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_size(&mut self, size: PaperSize,
+                    orientation: Orientation) -> Result<&mut Self, Error> {
+        let size = types::paper_size_as_int(size);
+        let orientation = types::orientation_as_int(orientation);
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetSize(self.handle, size, orientation)
+        }));
+        Ok(self)
+    }
+
+    /// Sets the clockwise rotation, in degrees, applied to the page when it is displayed or
+    /// printed.
+    ///
+    /// An `Error::PageInvalidRotateValue` is returned if `degrees` is not a multiple of 90.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_rotate(&mut self, degrees: u16) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_SetRotate(self.handle, degrees) }));
+        Ok(self)
+    }
+
     /// Returns the width of the line used to stroke a path.
     ///
     /// The default value is `1`.
@@ -335,6 +369,150 @@ impl Page {
         }
     }
 
+    /// Returns the depth of the graphics state stack.
+    ///
+    /// The depth is `1` when no graphics state has been saved, and can grow up to `28`.
+    pub fn gstate_depth(&self) -> u32 {
+        unsafe { haru::HPDF_Page_GetGStateDepth(self.handle) }
+    }
+
+    /// Saves the current graphics state (colors, line styles, text state, etc.) to the graphics
+    /// state stack, to be restored later by `Page::restore_state`.
+    ///
+    /// An `Error::GstateLimitExceeded` is returned if the stack is already at its maximum depth of
+    /// `28`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn save_state(&mut self) -> Result<&mut Self, Error> {
+        if self.gstate_depth() >= MAX_GSTATE_DEPTH {
+            return Err(Error::GstateLimitExceeded);
+        }
+
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_GSave(self.handle) }));
+        Ok(self)
+    }
+
+    /// Restores the graphics state most recently saved by `Page::save_state`.
+    ///
+    /// An `Error::PageCannotRestoreGstate` is returned if no graphics state has been saved.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn restore_state(&mut self) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_GRestore(self.handle) }));
+        Ok(self)
+    }
+
+    /// Saves the current graphics state, runs `f`, then restores the graphics state, so that any
+    /// change made by `f` does not leak into subsequent drawing.
+    ///
+    /// The graphics state is restored even if `f` returns an error, in which case that error is
+    /// returned. Any state `f` saved without restoring is also discarded, and any text object or
+    /// path `f` left unfinished is ended first.
+    ///
+    /// # Examples
+    ///
+    /// ```norun
+    /// try!(page.with_saved_state(|page| {
+    ///     try!(page.set_rgb_stroke(1.0, 0.0, 0.0));
+    ///     try!(page.rectangle(Point::new(10.0, 10.0), Size::new(40.0, 30.0)));
+    ///     try!(page.stroke());
+    ///     Ok(())
+    /// }));
+    ///
+    /// // The stroke color is black again.
+    /// ```
+    pub fn with_saved_state<T, F>(&mut self, f: F) -> Result<T, Error>
+        where F: FnOnce(&mut Page) -> Result<T, Error>
+    {
+        let depth = self.gstate_depth();
+        try!(self.save_state());
+        let result = f(self);
+
+        // libharu only restores the graphics state in page description mode.
+        let mode = unsafe { haru::HPDF_Page_GetGMode(self.handle) };
+        let mut restored = if mode == haru::HPDF_GMODE_TEXT_OBJECT as haru::HPDF_UINT16 {
+            self.end_text().map(|_| ())
+        } else if mode == haru::HPDF_GMODE_PATH_OBJECT as haru::HPDF_UINT16 ||
+                  mode == haru::HPDF_GMODE_CLIPPING_PATH as haru::HPDF_UINT16 {
+            self.end_path().map(|_| ())
+        } else {
+            Ok(())
+        };
+        while restored.is_ok() && self.gstate_depth() > depth {
+            restored = self.restore_state().map(|_| ());
+        }
+
+        match result {
+            Ok(value) => restored.map(|()| value),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Applies the parameters of an extended graphics state (such as transparency) to the current
+    /// graphics state.
+    ///
+    /// The extended graphics state becomes read-only afterward.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_ext_gstate(&mut self, ext_gstate: &ExtGState) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetExtGState(self.handle, ext_gstate::get_handle(ext_gstate))
+        }));
+        Ok(self)
+    }
+
+    /// Returns the current transformation matrix (CTM), which maps user space coordinates to the
+    /// coordinates of the page.
+    ///
+    /// The default value is `Matrix::identity()`.
+    pub fn transform_matrix(&self) -> Matrix {
+        types::matrix_from_raw(unsafe { haru::HPDF_Page_GetTransMatrix(self.handle) })
+    }
+
+    /// Applies the given transformation to the current transformation matrix, so that the
+    /// transformation applies to everything drawn afterward.
+    ///
+    /// Use `Page::save_state` and `Page::restore_state` to undo a transformation.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn concat(&mut self, matrix: &Matrix) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_Concat(self.handle, matrix.a, matrix.b, matrix.c, matrix.d, matrix.x,
+                                   matrix.y)
+        }));
+        Ok(self)
+    }
+
+    /// Moves the origin of the coordinate system by `(x, y)`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn translate(&mut self, x: f32, y: f32) -> Result<&mut Self, Error> {
+        self.concat(&Matrix::translation(x, y))
+    }
+
+    /// Rotates the coordinate system counterclockwise about its origin. The angle is given in
+    /// degrees.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn rotate(&mut self, angle: f32) -> Result<&mut Self, Error> {
+        self.concat(&Matrix::rotation(angle))
+    }
+
+    /// Scales the coordinate system by the factors `(x, y)` relative to its origin.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn scale(&mut self, x: f32, y: f32) -> Result<&mut Self, Error> {
+        self.concat(&Matrix::scaling(x, y))
+    }
+
+    /// Skews the x-axis of the coordinate system by `x_angle` and its y-axis by `y_angle`. Angles
+    /// are given in degrees.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn skew(&mut self, x_angle: f32, y_angle: f32) -> Result<&mut Self, Error> {
+        self.concat(&Matrix::skew(x_angle, y_angle))
+    }
+
     /// Sets the starting point for the next path to the specified point.
     ///
     /// This operation conveniently returns a reference to itself for chaining commands.
@@ -513,6 +691,160 @@ impl Page {
         Ok(self)
     }
 
+    /// Creates a new destination pointing to this page, then returns it.
+    ///
+    /// The destination initially displays the page magnified to fit entirely within the window.
+    pub fn create_destination(&mut self) -> Result<Destination, Error> {
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_Page_CreateDestination(self.handle)
+        }));
+        Ok(destination::new(handle, self.doc.clone()))
+    }
+
+    /// Creates a link annotation in the given region of the page, which jumps to `destination`
+    /// when clicked, then returns it.
+    pub fn create_link_annotation(&mut self, lower_left: Point, size: Size,
+                                  destination: &Destination) -> Result<Annotation, Error> {
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_Page_CreateLinkAnnot(self.handle, types::rect_as_raw(lower_left, size),
+                                            destination::get_handle(destination))
+        }));
+        Ok(annotation::new(handle, self.doc.clone()))
+    }
+
+    /// Creates a link annotation in the given region of the page, which opens `uri` when clicked,
+    /// then returns it.
+    ///
+    /// An `Error::InvalidUri` is returned if `uri` is empty.
+    pub fn create_uri_annotation(&mut self, lower_left: Point, size: Size,
+                                 uri: &str) -> Result<Annotation, Error> {
+        let uri = try!(CString::new(uri));
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_Page_CreateURILinkAnnot(self.handle, types::rect_as_raw(lower_left, size),
+                                               uri.as_ptr())
+        }));
+        Ok(annotation::new(handle, self.doc.clone()))
+    }
+
+    /// Creates a text annotation (a "sticky note") in the given region of the page, then returns
+    /// it.
+    ///
+    /// The text is encoded with the document's current encoding, or as UTF-8 if there is none.
+    pub fn create_text_annotation(&mut self, lower_left: Point, size: Size,
+                                  text: &str) -> Result<Annotation, Error> {
+        let encoder = try!(self.doc.text_encoder(None));
+        let text = try!(encoder::encode(&self.doc, encoder, text));
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_Page_CreateTextAnnot(self.handle, types::rect_as_raw(lower_left, size),
+                                            text.text.as_ptr(), encoder)
+        }));
+        Ok(annotation::new(handle, self.doc.clone()))
+    }
+
+    /// Creates a markup annotation covering the given region of the page, then returns it.
+    ///
+    /// The text is displayed in the annotation's pop-up window (or directly on the page for
+    /// `MarkupKind::FreeText`). It is encoded with the document's current encoding, or as UTF-8 if
+    /// there is none.
+    pub fn create_markup_annotation(&mut self, kind: MarkupKind, lower_left: Point, size: Size,
+                                    text: &str) -> Result<Annotation, Error> {
+        let create = match kind {
+            MarkupKind::Highlight => haru::HPDF_Page_CreateHighlightAnnot,
+            MarkupKind::Underline => haru::HPDF_Page_CreateUnderlineAnnot,
+            MarkupKind::Squiggly => haru::HPDF_Page_CreateSquigglyAnnot,
+            MarkupKind::StrikeOut => haru::HPDF_Page_CreateStrikeOutAnnot,
+            MarkupKind::FreeText => haru::HPDF_Page_CreateFreeTextAnnot,
+            MarkupKind::Square => haru::HPDF_Page_CreateSquareAnnot,
+            MarkupKind::Circle => haru::HPDF_Page_CreateCircleAnnot,
+        };
+
+        let encoder = try!(self.doc.text_encoder(None));
+        let text = try!(encoder::encode(&self.doc, encoder, text));
+        let rect = types::rect_as_raw(lower_left, size);
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            create(self.handle, rect, text.text.as_ptr(), encoder)
+        }));
+
+        match kind {
+            MarkupKind::Highlight | MarkupKind::Underline | MarkupKind::Squiggly |
+            MarkupKind::StrikeOut => {
+                // Text markup annotations are drawn along their quadrilateral, not their
+                // rectangle, so make the two coincide.
+                let point = |x, y| haru::HPDF_Point { x: x, y: y };
+                try!(self.doc.check_error(unsafe {
+                    haru::HPDF_TextMarkupAnnot_SetQuadPoints(handle,
+                                                             point(rect.left, rect.bottom),
+                                                             point(rect.right, rect.bottom),
+                                                             point(rect.right, rect.top),
+                                                             point(rect.left, rect.top))
+                }));
+            },
+            _ => (),
+        }
+
+        Ok(annotation::new(handle, self.doc.clone()))
+    }
+
+    /// Creates a line annotation from `start` to `end`, then returns it.
+    ///
+    /// The text is displayed in the annotation's pop-up window. It is encoded with the document's
+    /// current encoding, or as UTF-8 if there is none.
+    pub fn create_line_annotation(&mut self, start: Point, end: Point,
+                                  text: &str) -> Result<Annotation, Error> {
+        use haru::Enum__HPDF_LineAnnotEndingStyle::HPDF_LINE_ANNOT_NONE;
+
+        let encoder = try!(self.doc.text_encoder(None));
+        let text = try!(encoder::encode(&self.doc, encoder, text));
+        let handle = try!(self.doc.check_non_null_mut(unsafe {
+            haru::HPDF_Page_CreateLineAnnot(self.handle, text.text.as_ptr(), encoder)
+        }));
+
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_LineAnnot_SetPosition(handle,
+                                             haru::HPDF_Point { x: start.x, y: start.y },
+                                             HPDF_LINE_ANNOT_NONE,
+                                             haru::HPDF_Point { x: end.x, y: end.y },
+                                             HPDF_LINE_ANNOT_NONE)
+        }));
+
+        Ok(annotation::new(handle, self.doc.clone()))
+    }
+
+    /// Draws an image on the page, stretching it to fit the given size.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn draw_image(&mut self, image: &Image, lower_left: Point,
+                      size: Size) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_DrawImage(self.handle, image::get_handle(image), lower_left.x,
+                                      lower_left.y, size.width, size.height)
+        }));
+        Ok(self)
+    }
+
+    /// Intersects the current clipping region with the current path, using the non-zero winding
+    /// number rule. Nothing drawn afterward is visible outside of the clipping region.
+    ///
+    /// The clipping region takes effect once the current path is ended, so this operation must be
+    /// followed by `Page::end_path` or a path painting operation. Use `Page::save_state` and
+    /// `Page::restore_state` to undo the clip.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn clip(&mut self) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_Clip(self.handle) }));
+        Ok(self)
+    }
+
+    /// Intersects the current clipping region with the current path, using the even-odd rule.
+    ///
+    /// See `Page::clip` for details.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn eo_clip(&mut self) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_Eoclip(self.handle) }));
+        Ok(self)
+    }
+
     /// Returns the active font, if any.
     pub fn font(&self) -> Option<Font> {
         let handle = unsafe { haru::HPDF_Page_GetCurrentFont(self.handle) };
@@ -531,6 +863,38 @@ impl Page {
         }
     }
 
+    /// Returns the width of the given text when drawn with the active font, font size, character
+    /// spacing and word spacing. The horizontal scaling is not taken into account.
+    ///
+    /// An `Error::PageFontNotFound` is returned if no font is active.
+    pub fn text_width(&self, text: &str) -> Result<f32, Error> {
+        let text = try!(self.encode(text));
+        let width = unsafe { haru::HPDF_Page_TextWidth(self.handle, text.text.as_ptr()) };
+        try!(self.doc.check_error(unsafe { haru::HPDF_GetError(self.doc.0) }));
+        Ok(width)
+    }
+
+    /// Calculates how much of the given text fits on a single line of the given width when drawn
+    /// with the active font, font size, character spacing and word spacing. The horizontal scaling
+    /// is not taken into account.
+    ///
+    /// Returns the length in bytes of the longest prefix of `text` that fits, along with the width
+    /// of that prefix. If `word_wrap` is `true`, the text is only broken after whitespace, so the
+    /// returned length is `0` if not even the first word fits. A line feed always ends the line.
+    ///
+    /// An `Error::PageFontNotFound` is returned if no font is active.
+    pub fn measure_text(&self, text: &str, max_width: f32,
+                        word_wrap: bool) -> Result<(usize, f32), Error> {
+        let text = try!(self.encode(text));
+        let mut real_width = 0.0;
+        let len = unsafe {
+            haru::HPDF_Page_MeasureText(self.handle, text.text.as_ptr(), max_width,
+                                        word_wrap as haru::HPDF_BOOL, &mut real_width)
+        };
+        try!(self.doc.check_error(unsafe { haru::HPDF_GetError(self.doc.0) }));
+        Ok((text.source_offset(len as usize), real_width))
+    }
+
     /// Returns the current position for path painting.
     ///
     /// This method returns `(0.0, 0.0)` if the page is not currently in text mode (see
@@ -568,6 +932,101 @@ impl Page {
         Ok(self)
     }
 
+    /// Returns the extra space inserted between characters when drawing text.
+    ///
+    /// The default value is `0.0`.
+    pub fn char_space(&self) -> f32 {
+        unsafe { haru::HPDF_Page_GetCharSpace(self.handle) }
+    }
+
+    /// Sets the extra space inserted between characters when drawing text.
+    ///
+    /// The value must be between `-30.0` and `300.0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_char_space(&mut self, char_space: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetCharSpace(self.handle, char_space)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the extra space inserted after each space character when drawing text.
+    ///
+    /// The default value is `0.0`.
+    pub fn word_space(&self) -> f32 {
+        unsafe { haru::HPDF_Page_GetWordSpace(self.handle) }
+    }
+
+    /// Sets the extra space inserted after each space character when drawing text.
+    ///
+    /// The value must be between `-30.0` and `300.0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_word_space(&mut self, word_space: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetWordSpace(self.handle, word_space)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the horizontal scaling of text, as a percentage of the normal glyph width.
+    ///
+    /// The default value is `100.0`.
+    pub fn horizontal_scaling(&self) -> f32 {
+        // Sic.
+        unsafe { haru::HPDF_Page_GetHorizontalScalling(self.handle) }
+    }
+
+    /// Sets the horizontal scaling of text, as a percentage of the normal glyph width.
+    ///
+    /// The value must be between `10.0` and `300.0`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_horizontal_scaling(&mut self, scaling: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetHorizontalScalling(self.handle, scaling)
+        }));
+        Ok(self)
+    }
+
+    /// Returns the distance by which text is moved above (or, if negative, below) the baseline.
+    ///
+    /// The default value is `0.0`.
+    pub fn text_rise(&self) -> f32 {
+        unsafe { haru::HPDF_Page_GetTextRise(self.handle) }
+    }
+
+    /// Sets the distance by which text is moved above (or, if negative, below) the baseline, e.g.
+    /// for superscripts and subscripts.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_text_rise(&mut self, rise: f32) -> Result<&mut Self, Error> {
+        try!(self.doc.check_error(unsafe { haru::HPDF_Page_SetTextRise(self.handle, rise) }));
+        Ok(self)
+    }
+
+    /// Returns the way text is rendered.
+    ///
+    /// The default value is `TextRenderingMode::Fill`.
+    pub fn text_rendering_mode(&self) -> TextRenderingMode {
+        types::text_rendering_mode_from_int(unsafe {
+            haru::HPDF_Page_GetTextRenderingMode(self.handle)
+        })
+    }
+
+    /// Sets the way text is rendered.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_text_rendering_mode(&mut self,
+                                   mode: TextRenderingMode) -> Result<&mut Self, Error> {
+        let mode = types::text_rendering_mode_as_int(mode);
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_SetTextRenderingMode(self.handle, mode)
+        }));
+        Ok(self)
+    }
+
     /// Begins text object graphics mode with the text position `(0.0, 0.0)`.
     ///
     /// This operation conveniently returns a reference to itself for chaining commands.
@@ -588,9 +1047,47 @@ impl Page {
     ///
     /// This operation conveniently returns a reference to itself for chaining commands.
     pub fn text_out(&mut self, text: &str, baseline_left: Point) -> Result<&mut Self, Error> {
-        let text = try!(CString::new(text));
+        let text = try!(self.encode(text));
+        try!(self.doc.check_error(unsafe {
+            haru::HPDF_Page_TextOut(self.handle, baseline_left.x, baseline_left.y,
+                                    text.text.as_ptr())
+        }));
+        Ok(self)
+    }
+
+    /// Prints the text starting at the specified point, rotated counterclockwise about that point.
+    /// The angle is given in degrees.
+    ///
+    /// This sets the text matrix (see `Page::set_text_matrix`), so subsequent text in the same
+    /// text object is rotated as well.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn text_out_rotated(&mut self, text: &str, baseline_left: Point,
+                            angle: f32) -> Result<&mut Self, Error> {
+        let matrix = Matrix::rotation(angle) *
+                     Matrix::translation(baseline_left.x, baseline_left.y);
+        try!(self.set_text_matrix(&matrix));
+        self.show_text(text)
+    }
+
+    /// Returns the text matrix, which maps text space coordinates to user space coordinates.
+    ///
+    /// The text matrix is reset to `Matrix::identity()` by `Page::begin_text`.
+    pub fn text_matrix(&self) -> Matrix {
+        types::matrix_from_raw(unsafe { haru::HPDF_Page_GetTextMatrix(self.handle) })
+    }
+
+    /// Sets the text matrix, which maps text space coordinates to user space coordinates. This
+    /// allows text to be drawn at arbitrary positions, angles and skews. The text position is
+    /// moved to the origin of the new text space.
+    ///
+    /// This operation is only valid in between calls to `Page::begin_text` and `Page::end_text`.
+    ///
+    /// This operation conveniently returns a reference to itself for chaining commands.
+    pub fn set_text_matrix(&mut self, matrix: &Matrix) -> Result<&mut Self, Error> {
         try!(self.doc.check_error(unsafe {
-            haru::HPDF_Page_TextOut(self.handle, baseline_left.x, baseline_left.y, text.as_ptr())
+            haru::HPDF_Page_SetTextMatrix(self.handle, matrix.a, matrix.b, matrix.c, matrix.d,
+                                          matrix.x, matrix.y)
         }));
         Ok(self)
     }
@@ -610,30 +1107,54 @@ impl Page {
     ///
     /// This operation conveniently returns a reference to itself for chaining commands.
     pub fn show_text(&mut self, text: &str) -> Result<&mut Self, Error> {
-        let text = try!(CString::new(text));
+        let text = try!(self.encode(text));
         try!(self.doc.check_error(unsafe {
-            haru::HPDF_Page_ShowText(self.handle, text.as_ptr())
+            haru::HPDF_Page_ShowText(self.handle, text.text.as_ptr())
         }));
         Ok(self)
     }
 
     /// Prints the text inside the specified region using the specified alignment.
     ///
-    /// The text will be silently clipped if it does not entirely fit in the region.
+    /// The text will be silently clipped if it does not entirely fit in the region. Use
+    /// `text_rect_with_outcome` to find out which part of the text was printed.
     ///
     /// This operation conveniently returns a reference to itself for chaining commands.
     pub fn text_rect(&mut self, text: &str, lower_left: Point, size: Size,
                      alignment: TextAlignment) -> Result<&mut Self, Error> {
+        try!(self.text_rect_with_outcome(text, lower_left, size, alignment));
+        Ok(self)
+    }
+
+    /// Prints the text inside the specified region using the specified alignment, reporting how
+    /// much of the text fit in the region.
+    ///
+    /// Overflowing text is not an error; the remainder of the text is `&text[rendered_bytes..]`.
+    pub fn text_rect_with_outcome(&mut self, text: &str, lower_left: Point, size: Size,
+                                  alignment: TextAlignment) -> Result<TextRectOutcome, Error> {
+        let text = try!(self.encode(text));
+        let mut len = 0;
         let result = unsafe {
             haru::HPDF_Page_TextRect(self.handle, lower_left.x, lower_left.y + size.height,
-                                     lower_left.x + size.width, lower_left.y,
-                                     text.as_ptr() as *const i8,
-                                     types::text_alignment_as_int(alignment), ptr::null_mut())
+                                     lower_left.x + size.width, lower_left.y, text.text.as_ptr(),
+                                     types::text_alignment_as_int(alignment), &mut len)
         };
 
-        match self.doc.check_error(result) {
-            Ok(()) | Err(Error::PageInsufficientSpace) => Ok(self),
-            Err(err) => Err(err)
+        let overflowed = match self.doc.check_error(result) {
+            Ok(()) => false,
+            Err(Error::PageInsufficientSpace) => true,
+            Err(err) => return Err(err),
+        };
+        Ok(TextRectOutcome { rendered_bytes: text.source_offset(len as usize),
+                             overflowed: overflowed })
+    }
+
+    /// Transcodes the given text into the encoding of the active font. Without an active font, the
+    /// text is passed through unchanged.
+    fn encode(&self, text: &str) -> Result<EncodedText, Error> {
+        match self.font() {
+            Some(font) => font::encode(&font, text),
+            None => encoder::encode(&self.doc, ptr::null_mut(), text),
         }
     }
 }
@@ -1,4 +1,7 @@
+use error::Error;
 use haru;
+use std::ops::Mul;
+use std::os::raw::c_char;
 
 /// Describes how a page should be displayed.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -46,6 +49,383 @@ pub fn page_layout_from_int(layout: haru::HPDF_PageLayout) -> PageLayout {
     }
 }
 
+/// A list of all standard paper sizes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaperSize {
+    /// 8½ × 11 inches.
+    Letter,
+    /// 8½ × 14 inches.
+    Legal,
+    /// 297 × 420 millimeters.
+    A3,
+    /// 210 × 297 millimeters.
+    A4,
+    /// 148 × 210 millimeters.
+    A5,
+    /// 250 × 353 millimeters.
+    B4,
+    /// 176 × 250 millimeters.
+    B5,
+    /// 7¼ × 10½ inches.
+    Executive,
+    /// 4 × 6 inches.
+    Us4x6,
+    /// 4 × 8 inches.
+    Us4x8,
+    /// 5 × 7 inches.
+    Us5x7,
+    /// 4⅛ × 9½ inches (No. 10 envelope).
+    Comm10,
+}
+
+/// Converts a `PaperSize` to its corresponding internal code.
+pub fn paper_size_as_int(size: PaperSize) -> haru::HPDF_PageSizes {
+    use haru::Enum__HPDF_PageSizes::*;
+
+    match size {
+        PaperSize::Letter => HPDF_PAGE_SIZE_LETTER,
+        PaperSize::Legal => HPDF_PAGE_SIZE_LEGAL,
+        PaperSize::A3 => HPDF_PAGE_SIZE_A3,
+        PaperSize::A4 => HPDF_PAGE_SIZE_A4,
+        PaperSize::A5 => HPDF_PAGE_SIZE_A5,
+        PaperSize::B4 => HPDF_PAGE_SIZE_B4,
+        PaperSize::B5 => HPDF_PAGE_SIZE_B5,
+        PaperSize::Executive => HPDF_PAGE_SIZE_EXECUTIVE,
+        PaperSize::Us4x6 => HPDF_PAGE_SIZE_US4x6,
+        PaperSize::Us4x8 => HPDF_PAGE_SIZE_US4x8,
+        PaperSize::Us5x7 => HPDF_PAGE_SIZE_US5x7,
+        PaperSize::Comm10 => HPDF_PAGE_SIZE_COMM10,
+    }
+}
+
+/// Describes which edge of the paper is the top of a page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    /// The shorter edge is the top of the page.
+    Portrait,
+    /// The longer edge is the top of the page.
+    Landscape,
+}
+
+/// Converts an `Orientation` to its corresponding internal code.
+pub fn orientation_as_int(orientation: Orientation) -> haru::HPDF_PageDirection {
+    use haru::Enum__HPDF_PageDirection::*;
+
+    match orientation {
+        Orientation::Portrait => HPDF_PAGE_PORTRAIT,
+        Orientation::Landscape => HPDF_PAGE_LANDSCAPE,
+    }
+}
+
+/// A list of all numbering styles used by page labels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PageNumberStyle {
+    /// Arabic numerals (1, 2, 3, ...).
+    Decimal,
+    /// Uppercase roman numerals (I, II, III, ...).
+    UpperRoman,
+    /// Lowercase roman numerals (i, ii, iii, ...).
+    LowerRoman,
+    /// Uppercase letters (A, B, C, ..., Z, AA, BB, ...).
+    UpperLetters,
+    /// Lowercase letters (a, b, c, ..., z, aa, bb, ...).
+    LowerLetters,
+}
+
+/// Converts a `PageNumberStyle` to its corresponding internal code.
+pub fn page_number_style_as_int(style: PageNumberStyle) -> haru::HPDF_PageNumStyle {
+    use haru::Enum__HPDF_PageNumStyle::*;
+
+    match style {
+        PageNumberStyle::Decimal => HPDF_PAGE_NUM_STYLE_DECIMAL,
+        PageNumberStyle::UpperRoman => HPDF_PAGE_NUM_STYLE_UPPER_ROMAN,
+        PageNumberStyle::LowerRoman => HPDF_PAGE_NUM_STYLE_LOWER_ROMAN,
+        PageNumberStyle::UpperLetters => HPDF_PAGE_NUM_STYLE_UPPER_LETTERS,
+        PageNumberStyle::LowerLetters => HPDF_PAGE_NUM_STYLE_LOWER_LETTERS,
+    }
+}
+
+/// Describes which kinds of document streams are compressed (using the Flate algorithm) when a
+/// document is saved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CompressionMode {
+    /// Compress the contents streams of pages.
+    pub text: bool,
+    /// Compress the streams of image objects.
+    pub image: bool,
+    /// Compress other data, such as fonts and cmaps.
+    pub metadata: bool,
+}
+
+impl CompressionMode {
+    /// Creates a compression mode where no stream is compressed.
+    pub fn none() -> CompressionMode {
+        CompressionMode { text: false, image: false, metadata: false }
+    }
+
+    /// Creates a compression mode where every kind of stream is compressed.
+    pub fn all() -> CompressionMode {
+        CompressionMode { text: true, image: true, metadata: true }
+    }
+}
+
+impl Default for CompressionMode {
+    fn default() -> CompressionMode {
+        CompressionMode::none()
+    }
+}
+
+/// Converts a `CompressionMode` to its corresponding internal flags.
+pub fn compression_mode_as_int(mode: CompressionMode) -> haru::HPDF_UINT {
+    // `HPDF_COMP_TEXT`, `HPDF_COMP_IMAGE` and `HPDF_COMP_METADATA`, respectively.
+    (if mode.text { 0x01 } else { 0 }) |
+        (if mode.image { 0x02 } else { 0 }) |
+        (if mode.metadata { 0x04 } else { 0 })
+}
+
+/// Describes the operations a user is allowed to perform on an encrypted document when it is
+/// opened with the user password.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Permissions {
+    /// The user may print the document.
+    pub print: bool,
+    /// The user may add or modify annotations and fill in form fields.
+    pub edit: bool,
+    /// The user may copy text and graphics from the document.
+    pub copy: bool,
+    /// The user may modify the contents of the document, such as its pages.
+    pub edit_all: bool,
+}
+
+impl Permissions {
+    /// Creates a set of permissions that only allows reading the document.
+    pub fn read_only() -> Permissions {
+        Permissions { print: false, edit: false, copy: false, edit_all: false }
+    }
+
+    /// Creates a set of permissions that allows every operation.
+    pub fn all() -> Permissions {
+        Permissions { print: true, edit: true, copy: true, edit_all: true }
+    }
+}
+
+/// Converts a `Permissions` to its corresponding internal flags.
+pub fn permissions_as_int(permissions: Permissions) -> haru::HPDF_UINT {
+    // `HPDF_ENABLE_PRINT`, `HPDF_ENABLE_EDIT`, `HPDF_ENABLE_COPY` and `HPDF_ENABLE_EDIT_ALL`,
+    // respectively. Reading (`HPDF_ENABLE_READ`) is always allowed.
+    (if permissions.print { 0x04 } else { 0 }) |
+        (if permissions.edit { 0x20 } else { 0 }) |
+        (if permissions.copy { 0x10 } else { 0 }) |
+        (if permissions.edit_all { 0x08 } else { 0 })
+}
+
+/// A list of all supported encryption algorithms.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EncryptionMode {
+    /// RC4 encryption with a 40-bit key (PDF 1.3, revision 2).
+    Rc4Key40,
+    /// RC4 encryption with a 128-bit key (PDF 1.4, revision 3).
+    Rc4Key128,
+}
+
+/// Converts an `EncryptionMode` to its corresponding internal mode and key length in bytes.
+pub fn encryption_mode_as_int(mode: EncryptionMode) -> (haru::HPDF_EncryptMode, haru::HPDF_UINT) {
+    use haru::Enum__HPDF_EncryptMode::*;
+
+    match mode {
+        EncryptionMode::Rc4Key40 => (HPDF_ENCRYPT_R2, 5),
+        EncryptionMode::Rc4Key128 => (HPDF_ENCRYPT_R3, 16),
+    }
+}
+
+/// A list of all textual entries of the document information dictionary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InfoKey {
+    /// The name of the person who created the document.
+    Author,
+    /// The name of the application that created the original document.
+    Creator,
+    /// The name of the application that converted the document to PDF.
+    Producer,
+    /// The title of the document.
+    Title,
+    /// The subject of the document.
+    Subject,
+    /// Keywords associated with the document.
+    Keywords,
+}
+
+/// Converts an `InfoKey` to its corresponding internal code.
+pub fn info_key_as_int(key: InfoKey) -> haru::HPDF_InfoType {
+    use haru::Enum__HPDF_InfoType::*;
+
+    match key {
+        InfoKey::Author => HPDF_INFO_AUTHOR,
+        InfoKey::Creator => HPDF_INFO_CREATOR,
+        InfoKey::Producer => HPDF_INFO_PRODUCER,
+        InfoKey::Title => HPDF_INFO_TITLE,
+        InfoKey::Subject => HPDF_INFO_SUBJECT,
+        InfoKey::Keywords => HPDF_INFO_KEYWORDS,
+    }
+}
+
+/// Converts an `InfoKey` to its corresponding NUL-terminated dictionary key.
+pub fn info_key_name(key: InfoKey) -> &'static [u8] {
+    match key {
+        InfoKey::Author => b"Author\0",
+        InfoKey::Creator => b"Creator\0",
+        InfoKey::Producer => b"Producer\0",
+        InfoKey::Title => b"Title\0",
+        InfoKey::Subject => b"Subject\0",
+        InfoKey::Keywords => b"Keywords\0",
+    }
+}
+
+/// A calendar date and time of day, as stored in the document information dictionary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateTime {
+    /// The year, between `0` and `9999`.
+    pub year: i32,
+    /// The month, between `1` and `12`.
+    pub month: u32,
+    /// The day of the month, starting at `1`.
+    pub day: u32,
+    /// The hour, between `0` and `23`.
+    pub hour: u32,
+    /// The minute, between `0` and `59`.
+    pub minute: u32,
+    /// The second, between `0` and `59`.
+    pub second: u32,
+    /// The offset from UTC in minutes, or `None` if the time zone is unknown. The magnitude of the
+    /// offset must be less than 24 hours.
+    pub utc_offset: Option<i32>,
+}
+
+impl DateTime {
+    /// Creates a new date and time in an unknown time zone, returning `Error::InvalidDateTime` if
+    /// any of the fields are out of range.
+    pub fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32,
+               second: u32) -> Result<DateTime, Error> {
+        let date_time = DateTime {
+            year: year,
+            month: month,
+            day: day,
+            hour: hour,
+            minute: minute,
+            second: second,
+            utc_offset: None,
+        };
+        try!(date_time.validate());
+        Ok(date_time)
+    }
+
+    /// Returns a copy of this date and time with the given offset from UTC in minutes, returning
+    /// `Error::InvalidDateTime` if the offset is out of range.
+    pub fn with_utc_offset(self, utc_offset: i32) -> Result<DateTime, Error> {
+        let date_time = DateTime { utc_offset: Some(utc_offset), ..self };
+        try!(date_time.validate());
+        Ok(date_time)
+    }
+
+    /// Returns `Error::InvalidDateTime` if any of the fields are out of range.
+    pub fn validate(&self) -> Result<(), Error> {
+        let leap_year = self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0);
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => return Err(Error::InvalidDateTime),
+        };
+
+        let valid_offset = self.utc_offset.map_or(true, |offset| offset.abs() < 24 * 60);
+        if self.year < 0 || self.year > 9999 || self.day < 1 || self.day > days_in_month ||
+           self.hour > 23 || self.minute > 59 || self.second > 59 || !valid_offset {
+            return Err(Error::InvalidDateTime);
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a `DateTime` to its corresponding internal structure.
+pub fn date_time_as_raw(date_time: DateTime) -> haru::HPDF_Date {
+    let (ind, offset) = match date_time.utc_offset {
+        None => (b' ', 0),
+        Some(0) => (b'Z', 0),
+        Some(offset) if offset < 0 => (b'-', -offset),
+        Some(offset) => (b'+', offset),
+    };
+
+    haru::HPDF_Date {
+        year: date_time.year,
+        month: date_time.month as i32,
+        day: date_time.day as i32,
+        hour: date_time.hour as i32,
+        minutes: date_time.minute as i32,
+        seconds: date_time.second as i32,
+        ind: ind as c_char,
+        off_hour: offset / 60,
+        off_minutes: offset % 60,
+    }
+}
+
+/// Parses a PDF date string of the form `D:YYYYMMDDHHmmSS[Z|+HH'mm'|-HH'mm']`, as written by
+/// libharu, into a `DateTime`.
+///
+/// Returns `None` if the string is malformed or describes an invalid date.
+pub fn date_time_from_str(s: &[u8]) -> Option<DateTime> {
+    // Parses a run of ASCII digits into a number.
+    fn number(s: &[u8]) -> Option<u32> {
+        s.iter().fold(Some(0), |acc, &c| {
+            acc.and_then(|acc| (c as char).to_digit(10).map(|digit| acc * 10 + digit))
+        })
+    }
+
+    if s.len() < 16 || &s[..2] != b"D:" {
+        return None;
+    }
+
+    let utc_offset = match &s[16..] {
+        b"" => None,
+        b"Z" | b"Z00'00'" => Some(0),
+        rest if rest.len() == 7 && rest[3] == b'\'' && rest[6] == b'\'' => {
+            let minutes = match (number(&rest[1..3]), number(&rest[4..6])) {
+                (Some(hours), Some(minutes)) => (hours * 60 + minutes) as i32,
+                _ => return None,
+            };
+
+            match rest[0] {
+                b'+' => Some(minutes),
+                b'-' => Some(-minutes),
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    let fields = [&s[2..6], &s[6..8], &s[8..10], &s[10..12], &s[12..14], &s[14..16]];
+    let mut values = [0; 6];
+    for (value, field) in values.iter_mut().zip(fields.iter()) {
+        *value = match number(field) {
+            Some(number) => number,
+            None => return None,
+        };
+    }
+
+    let date_time = DateTime {
+        year: values[0] as i32,
+        month: values[1],
+        day: values[2],
+        hour: values[3],
+        minute: values[4],
+        second: values[5],
+        utc_offset: utc_offset,
+    };
+
+    date_time.validate().ok().map(|()| date_time)
+}
+
 /// A list of all types of stroke line caps.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LineCap {
@@ -155,6 +535,106 @@ impl Size {
     }
 }
 
+/// A 2-dimensional affine transformation matrix, as used for the current transformation matrix
+/// (CTM) of a page.
+///
+/// A point `(x, y)` is transformed into `(a * x + c * y + x, b * x + d * y + y)`, where the
+/// final terms are the matrix's own `x` and `y` translation components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix {
+    /// The horizontal scaling term, which also contributes to rotation.
+    pub a: f32,
+    /// The vertical skewing term, which also contributes to rotation.
+    pub b: f32,
+    /// The horizontal skewing term, which also contributes to rotation.
+    pub c: f32,
+    /// The vertical scaling term, which also contributes to rotation.
+    pub d: f32,
+    /// The horizontal translation.
+    pub x: f32,
+    /// The vertical translation.
+    pub y: f32,
+}
+
+impl Matrix {
+    /// Creates a new matrix from its six components.
+    pub fn new(a: f32, b: f32, c: f32, d: f32, x: f32, y: f32) -> Matrix {
+        Matrix { a: a, b: b, c: c, d: d, x: x, y: y }
+    }
+
+    /// Creates the identity matrix, which leaves points unchanged.
+    pub fn identity() -> Matrix {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a matrix that moves points by `(x, y)`.
+    pub fn translation(x: f32, y: f32) -> Matrix {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Creates a matrix that rotates points counterclockwise about the origin. The angle is given
+    /// in degrees.
+    pub fn rotation(angle: f32) -> Matrix {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates a matrix that scales points by the factors `(x, y)` relative to the origin.
+    pub fn scaling(x: f32, y: f32) -> Matrix {
+        Matrix::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a matrix that skews the x-axis by `x_angle` and the y-axis by `y_angle`. Angles
+    /// are given in degrees.
+    pub fn skew(x_angle: f32, y_angle: f32) -> Matrix {
+        Matrix::new(1.0, x_angle.to_radians().tan(), y_angle.to_radians().tan(), 1.0, 0.0, 0.0)
+    }
+
+    /// Returns the inverse of this matrix, or `None` if the matrix is not invertible.
+    pub fn invert(&self) -> Option<Matrix> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        Some(Matrix::new(self.d / det, -self.b / det, -self.c / det, self.a / det,
+                         (self.c * self.y - self.d * self.x) / det,
+                         (self.b * self.x - self.a * self.y) / det))
+    }
+
+    /// Returns the given point transformed by this matrix.
+    pub fn transform_point(&self, point: Point) -> Point {
+        Point::new(self.a * point.x + self.c * point.y + self.x,
+                   self.b * point.x + self.d * point.y + self.y)
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Matrix {
+        Matrix::identity()
+    }
+}
+
+/// Concatenates two matrices. The resulting matrix applies the transformation of `self`, followed
+/// by the transformation of `rhs`.
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        Matrix::new(self.a * rhs.a + self.b * rhs.c,
+                    self.a * rhs.b + self.b * rhs.d,
+                    self.c * rhs.a + self.d * rhs.c,
+                    self.c * rhs.b + self.d * rhs.d,
+                    self.x * rhs.a + self.y * rhs.c + rhs.x,
+                    self.x * rhs.b + self.y * rhs.d + rhs.y)
+    }
+}
+
+/// Converts an internal transformation matrix to a `Matrix`.
+pub fn matrix_from_raw(matrix: haru::HPDF_TransMatrix) -> Matrix {
+    Matrix::new(matrix.a, matrix.b, matrix.c, matrix.d, matrix.x, matrix.y)
+}
+
 /// A list of all types of color spaces.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ColorSpace {
@@ -171,6 +651,25 @@ pub enum ColorSpace {
     Pattern,
 }
 
+/// Converts a `ColorSpace` to its corresponding internal code.
+pub fn color_space_as_int(color_space: ColorSpace) -> haru::HPDF_ColorSpace {
+    use haru::Enum__HPDF_ColorSpace::*;
+
+    match color_space {
+        ColorSpace::DeviceGray => HPDF_CS_DEVICE_GRAY,
+        ColorSpace::DeviceRgb => HPDF_CS_DEVICE_RGB,
+        ColorSpace::DeviceCmyk => HPDF_CS_DEVICE_CMYK,
+        ColorSpace::CalGray => HPDF_CS_CAL_GRAY,
+        ColorSpace::CalRgb => HPDF_CS_CAL_RGB,
+        ColorSpace::Lab => HPDF_CS_LAB,
+        ColorSpace::IccBased => HPDF_CS_ICC_BASED,
+        ColorSpace::Separation => HPDF_CS_SEPARATION,
+        ColorSpace::DeviceN => HPDF_CS_DEVICE_N,
+        ColorSpace::Indexed => HPDF_CS_INDEXED,
+        ColorSpace::Pattern => HPDF_CS_PATTERN,
+    }
+}
+
 /// Returns a `ColorSpace` for the internal color space code.
 ///
 /// # Panics
@@ -195,6 +694,28 @@ pub fn color_space_from_int(color_space: haru::HPDF_ColorSpace) -> ColorSpace {
     }
 }
 
+/// Returns a `ColorSpace` for the color space name used in PDF objects (e.g. `DeviceRGB`).
+///
+/// # Panics
+///
+/// Panics if the color space name is unrecognized or unsupported.
+pub fn color_space_from_name(name: &[u8]) -> ColorSpace {
+    match name {
+        b"DeviceGray" => ColorSpace::DeviceGray,
+        b"DeviceRGB" => ColorSpace::DeviceRgb,
+        b"DeviceCMYK" => ColorSpace::DeviceCmyk,
+        b"CalGray" => ColorSpace::CalGray,
+        b"CalRGB" => ColorSpace::CalRgb,
+        b"Lab" => ColorSpace::Lab,
+        b"ICCBased" => ColorSpace::IccBased,
+        b"Separation" => ColorSpace::Separation,
+        b"DeviceN" => ColorSpace::DeviceN,
+        b"Indexed" => ColorSpace::Indexed,
+        b"Pattern" => ColorSpace::Pattern,
+        _ => panic!("Unrecognized or unsupported color space name"),
+    }
+}
+
 /// Describes how text should be aligned when displayed.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TextAlignment {
@@ -215,3 +736,591 @@ pub fn text_alignment_as_int(layout: TextAlignment) -> haru::HPDF_TextAlignment
         TextAlignment::Justify => HPDF_TALIGN_JUSTIFY,
     }
 }
+
+/// The result of printing text inside a region.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextRectOutcome {
+    /// The length in bytes of the leading part of the text that was printed.
+    pub rendered_bytes: usize,
+    /// Whether the text did not entirely fit in the region. If `true`, the text starting at
+    /// `rendered_bytes` was not printed.
+    pub overflowed: bool,
+}
+
+/// Converts a rectangle described by its lower-left corner and size to its corresponding internal
+/// structure.
+pub fn rect_as_raw(lower_left: Point, size: Size) -> haru::HPDF_Rect {
+    haru::HPDF_Rect {
+        left: lower_left.x,
+        bottom: lower_left.y,
+        right: lower_left.x + size.width,
+        top: lower_left.y + size.height,
+    }
+}
+
+/// Describes how the border of an annotation is drawn.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BorderStyle {
+    /// A solid rectangle surrounding the annotation.
+    Solid,
+    /// A dashed rectangle surrounding the annotation. The dash pattern consists of `on` units of
+    /// solid line followed by `off` units of gap, starting `phase` units into the pattern.
+    Dashed { on: u16, off: u16, phase: u16 },
+    /// A simulated embossed rectangle that appears to be raised above the page.
+    Beveled,
+    /// A simulated engraved rectangle that appears to be recessed below the page.
+    Inset,
+    /// A single line along the bottom of the annotation.
+    Underlined,
+}
+
+/// Converts a `BorderStyle` to its corresponding internal code and dash pattern
+/// `(subtype, dash_on, dash_off, dash_phase)`.
+pub fn border_style_as_raw(style: BorderStyle) -> (haru::HPDF_BSSubtype, u16, u16, u16) {
+    use haru::Enum__HPDF_BSSubtype::*;
+
+    match style {
+        BorderStyle::Solid => (HPDF_BS_SOLID, 0, 0, 0),
+        BorderStyle::Dashed { on, off, phase } => (HPDF_BS_DASHED, on, off, phase),
+        BorderStyle::Beveled => (HPDF_BS_BEVELED, 0, 0, 0),
+        BorderStyle::Inset => (HPDF_BS_INSET, 0, 0, 0),
+        BorderStyle::Underlined => (HPDF_BS_UNDERLINED, 0, 0, 0),
+    }
+}
+
+/// Describes how a link annotation is displayed while the mouse button is pressed inside of it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HighlightMode {
+    /// No highlighting.
+    None,
+    /// The contents of the annotation are inverted.
+    InvertBox,
+    /// The border of the annotation is inverted.
+    InvertBorder,
+    /// The annotation appears to be pushed below the page.
+    DownAppearance,
+}
+
+/// Converts a `HighlightMode` to its corresponding internal code.
+pub fn highlight_mode_as_int(mode: HighlightMode) -> haru::HPDF_AnnotHighlightMode {
+    use haru::Enum__HPDF_AnnotHighlightMode::*;
+
+    match mode {
+        // Sic.
+        HighlightMode::None => HPDF_ANNOT_NO_HIGHTLIGHT,
+        HighlightMode::InvertBox => HPDF_ANNOT_INVERT_BOX,
+        HighlightMode::InvertBorder => HPDF_ANNOT_INVERT_BORDER,
+        HighlightMode::DownAppearance => HPDF_ANNOT_DOWN_APPEARANCE,
+    }
+}
+
+/// A list of all icons that can represent a text annotation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnnotationIcon {
+    /// A speech balloon.
+    Comment,
+    /// A key.
+    Key,
+    /// A sheet of paper with lines of text.
+    Note,
+    /// A question mark.
+    Help,
+    /// A marker for the start of a new paragraph.
+    NewParagraph,
+    /// A paragraph sign (¶).
+    Paragraph,
+    /// A caret, marking inserted text.
+    Insert,
+}
+
+/// Converts an `AnnotationIcon` to its corresponding internal code.
+pub fn annotation_icon_as_int(icon: AnnotationIcon) -> haru::HPDF_AnnotIcon {
+    use haru::Enum__HPDF_AnnotIcon::*;
+
+    match icon {
+        AnnotationIcon::Comment => HPDF_ANNOT_ICON_COMMENT,
+        AnnotationIcon::Key => HPDF_ANNOT_ICON_KEY,
+        AnnotationIcon::Note => HPDF_ANNOT_ICON_NOTE,
+        AnnotationIcon::Help => HPDF_ANNOT_ICON_HELP,
+        AnnotationIcon::NewParagraph => HPDF_ANNOT_ICON_NEW_PARAGRAPH,
+        AnnotationIcon::Paragraph => HPDF_ANNOT_ICON_PARAGRAPH,
+        AnnotationIcon::Insert => HPDF_ANNOT_ICON_INSERT,
+    }
+}
+
+/// A list of all kinds of markup annotations that cover a rectangular region of a page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarkupKind {
+    /// Highlights the text in the region.
+    Highlight,
+    /// Underlines the text in the region.
+    Underline,
+    /// Underlines the text in the region with a jagged line.
+    Squiggly,
+    /// Strikes out the text in the region.
+    StrikeOut,
+    /// Displays the annotation's text directly on the page.
+    FreeText,
+    /// Draws a rectangle filling the region.
+    Square,
+    /// Draws an ellipse filling the region.
+    Circle,
+}
+
+/// A list of all blend modes, which describe how colors being drawn are combined with the colors
+/// already on the page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlendMode {
+    /// The drawn color replaces the backdrop color.
+    Normal,
+    /// The colors are multiplied, which never results in a lighter color.
+    Multiply,
+    /// The complements of the colors are multiplied, which never results in a darker color.
+    Screen,
+    /// The colors are multiplied or screened, depending on the backdrop color.
+    Overlay,
+    /// The darker of the two colors is selected.
+    Darken,
+    /// The lighter of the two colors is selected.
+    Lighten,
+    /// The backdrop color is brightened to reflect the drawn color.
+    ColorDodge,
+    /// The backdrop color is darkened to reflect the drawn color.
+    ColorBurn,
+    /// The colors are multiplied or screened, depending on the drawn color.
+    HardLight,
+    /// The colors are darkened or lightened, depending on the drawn color.
+    SoftLight,
+    /// The darker of the two colors is subtracted from the lighter one.
+    Difference,
+    /// Like `Difference`, but with lower contrast.
+    Exclusion,
+}
+
+/// Converts a `BlendMode` to its corresponding internal code.
+pub fn blend_mode_as_int(mode: BlendMode) -> haru::HPDF_BlendMode {
+    use haru::Enum__HPDF_BlendMode::*;
+
+    match mode {
+        BlendMode::Normal => HPDF_BM_NORMAL,
+        BlendMode::Multiply => HPDF_BM_MULTIPLY,
+        BlendMode::Screen => HPDF_BM_SCREEN,
+        BlendMode::Overlay => HPDF_BM_OVERLAY,
+        BlendMode::Darken => HPDF_BM_DARKEN,
+        BlendMode::Lighten => HPDF_BM_LIGHTEN,
+        BlendMode::ColorDodge => HPDF_BM_COLOR_DODGE,
+        // Sic.
+        BlendMode::ColorBurn => HPDF_BM_COLOR_BUM,
+        BlendMode::HardLight => HPDF_BM_HARD_LIGHT,
+        BlendMode::SoftLight => HPDF_BM_SOFT_LIGHT,
+        BlendMode::Difference => HPDF_BM_DIFFERENCE,
+        // Sic.
+        BlendMode::Exclusion => HPDF_BM_EXCLUSHON,
+    }
+}
+
+/// A list of all ways text can be rendered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextRenderingMode {
+    /// Fill the glyph outlines.
+    Fill,
+    /// Stroke the glyph outlines.
+    Stroke,
+    /// Fill, then stroke the glyph outlines.
+    FillStroke,
+    /// Neither fill nor stroke the glyph outlines, making the text invisible (but still
+    /// selectable and searchable).
+    Invisible,
+    /// Fill the glyph outlines, then add them to the clipping path.
+    FillClip,
+    /// Stroke the glyph outlines, then add them to the clipping path.
+    StrokeClip,
+    /// Fill, then stroke the glyph outlines, then add them to the clipping path.
+    FillStrokeClip,
+    /// Add the glyph outlines to the clipping path.
+    Clip,
+}
+
+/// Converts a `TextRenderingMode` to its corresponding internal code.
+pub fn text_rendering_mode_as_int(mode: TextRenderingMode) -> haru::HPDF_TextRenderingMode {
+    use haru::Enum__HPDF_TextRenderingMode::*;
+
+    match mode {
+        TextRenderingMode::Fill => HPDF_FILL,
+        TextRenderingMode::Stroke => HPDF_STROKE,
+        TextRenderingMode::FillStroke => HPDF_FILL_THEN_STROKE,
+        TextRenderingMode::Invisible => HPDF_INVISIBLE,
+        TextRenderingMode::FillClip => HPDF_FILL_CLIPPING,
+        TextRenderingMode::StrokeClip => HPDF_STROKE_CLIPPING,
+        TextRenderingMode::FillStrokeClip => HPDF_FILL_STROKE_CLIPPING,
+        TextRenderingMode::Clip => HPDF_CLIPPING,
+    }
+}
+
+/// Returns a `TextRenderingMode` for the internal text rendering mode code.
+///
+/// # Panics
+///
+/// Panics if the text rendering mode is unrecognized or unsupported.
+pub fn text_rendering_mode_from_int(mode: haru::HPDF_TextRenderingMode) -> TextRenderingMode {
+    use haru::Enum__HPDF_TextRenderingMode::*;
+
+    match mode {
+        HPDF_FILL => TextRenderingMode::Fill,
+        HPDF_STROKE => TextRenderingMode::Stroke,
+        HPDF_FILL_THEN_STROKE => TextRenderingMode::FillStroke,
+        HPDF_INVISIBLE => TextRenderingMode::Invisible,
+        HPDF_FILL_CLIPPING => TextRenderingMode::FillClip,
+        HPDF_STROKE_CLIPPING => TextRenderingMode::StrokeClip,
+        HPDF_FILL_STROKE_CLIPPING => TextRenderingMode::FillStrokeClip,
+        HPDF_CLIPPING => TextRenderingMode::Clip,
+        _ => panic!("Unrecognized or unsupported text rendering mode setting"),
+    }
+}
+
+/// One of the 14 standard Type 1 fonts that every PDF viewer provides, and which therefore never
+/// need to be embedded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StandardFont {
+    /// Helvetica, a sans-serif typeface.
+    Helvetica,
+    /// Helvetica in bold.
+    HelveticaBold,
+    /// Helvetica in oblique.
+    HelveticaOblique,
+    /// Helvetica in bold and oblique.
+    HelveticaBoldOblique,
+    /// Times, a serif typeface.
+    TimesRoman,
+    /// Times in bold.
+    TimesBold,
+    /// Times in italic.
+    TimesItalic,
+    /// Times in bold and italic.
+    TimesBoldItalic,
+    /// Courier, a monospaced typeface.
+    Courier,
+    /// Courier in bold.
+    CourierBold,
+    /// Courier in oblique.
+    CourierOblique,
+    /// Courier in bold and oblique.
+    CourierBoldOblique,
+    /// Greek letters and mathematical symbols. Uses its own built-in encoding.
+    Symbol,
+    /// Decorative symbols such as arrows, stars and check marks. Uses its own built-in encoding.
+    ZapfDingbats,
+}
+
+/// Converts a `StandardFont` to its corresponding NUL-terminated font name.
+pub fn standard_font_name(font: StandardFont) -> &'static [u8] {
+    match font {
+        StandardFont::Helvetica => b"Helvetica\0",
+        StandardFont::HelveticaBold => b"Helvetica-Bold\0",
+        StandardFont::HelveticaOblique => b"Helvetica-Oblique\0",
+        StandardFont::HelveticaBoldOblique => b"Helvetica-BoldOblique\0",
+        StandardFont::TimesRoman => b"Times-Roman\0",
+        StandardFont::TimesBold => b"Times-Bold\0",
+        StandardFont::TimesItalic => b"Times-Italic\0",
+        StandardFont::TimesBoldItalic => b"Times-BoldItalic\0",
+        StandardFont::Courier => b"Courier\0",
+        StandardFont::CourierBold => b"Courier-Bold\0",
+        StandardFont::CourierOblique => b"Courier-Oblique\0",
+        StandardFont::CourierBoldOblique => b"Courier-BoldOblique\0",
+        StandardFont::Symbol => b"Symbol\0",
+        StandardFont::ZapfDingbats => b"ZapfDingbats\0",
+    }
+}
+
+/// A language whose built-in CID fonts and encodings can be enabled for a document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CjkLanguage {
+    /// Japanese: the MS Mincho and MS Gothic fonts and the Shift-JIS and EUC-JP encodings.
+    Japanese,
+    /// Simplified Chinese: the SimSun and SimHei fonts and the EUC-CN and GBK encodings.
+    SimplifiedChinese,
+    /// Traditional Chinese: the MingLiU font and the Big Five encoding.
+    TraditionalChinese,
+    /// Korean: the Dotum and Batang fonts and the EUC-KR and Unified Hangul Code encodings.
+    Korean,
+}
+
+/// A built-in CID font family. Each family is available once the fonts of its language have been
+/// enabled on the document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CjkFont {
+    /// A Japanese serif font.
+    MsMincho,
+    /// A Japanese sans-serif font.
+    MsGothic,
+    /// A proportional Japanese serif font.
+    MsPMincho,
+    /// A proportional Japanese sans-serif font.
+    MsPGothic,
+    /// A simplified Chinese serif font.
+    SimSun,
+    /// A simplified Chinese sans-serif font.
+    SimHei,
+    /// A traditional Chinese serif font.
+    MingLiU,
+    /// A Korean sans-serif font.
+    Dotum,
+    /// A fixed-width Korean sans-serif font.
+    DotumChe,
+    /// A Korean serif font.
+    Batang,
+    /// A fixed-width Korean serif font.
+    BatangChe,
+}
+
+/// Converts a `CjkFont` to its corresponding NUL-terminated font name.
+pub fn cjk_font_name(font: CjkFont) -> &'static [u8] {
+    match font {
+        CjkFont::MsMincho => b"MS-Mincho\0",
+        CjkFont::MsGothic => b"MS-Gothic\0",
+        CjkFont::MsPMincho => b"MS-PMincho\0",
+        CjkFont::MsPGothic => b"MS-PGothic\0",
+        CjkFont::SimSun => b"SimSun\0",
+        CjkFont::SimHei => b"SimHei\0",
+        CjkFont::MingLiU => b"MingLiU\0",
+        CjkFont::Dotum => b"Dotum\0",
+        CjkFont::DotumChe => b"DotumChe\0",
+        CjkFont::Batang => b"Batang\0",
+        CjkFont::BatangChe => b"BatangChe\0",
+    }
+}
+
+/// A built-in multi-byte CMap encoding. Each encoding is available once the encodings of its
+/// language have been enabled on the document.
+///
+/// Encodings ending in `H` lay text out horizontally, while those ending in `V` lay it out
+/// vertically.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CjkEncoding {
+    /// Microsoft Shift-JIS (Japanese).
+    Ms90RksjH,
+    /// `Ms90RksjH` for vertical writing.
+    Ms90RksjV,
+    /// Microsoft Shift-JIS with proportional Latin characters (Japanese).
+    Msp90RksjH,
+    /// EUC-JP (Japanese).
+    EucH,
+    /// `EucH` for vertical writing.
+    EucV,
+    /// EUC-CN (simplified Chinese).
+    GbEucH,
+    /// `GbEucH` for vertical writing.
+    GbEucV,
+    /// Microsoft GBK (simplified Chinese).
+    GbkEucH,
+    /// `GbkEucH` for vertical writing.
+    GbkEucV,
+    /// Big Five with ETen extensions (traditional Chinese).
+    EtenB5H,
+    /// `EtenB5H` for vertical writing.
+    EtenB5V,
+    /// EUC-KR (Korean).
+    KscEucH,
+    /// `KscEucH` for vertical writing.
+    KscEucV,
+    /// Microsoft Unified Hangul Code (Korean).
+    KscmsUhcH,
+    /// Microsoft Unified Hangul Code with half-width Latin characters (Korean).
+    KscmsUhcHwH,
+    /// `KscmsUhcHwH` for vertical writing.
+    KscmsUhcHwV,
+}
+
+/// Converts a `CjkEncoding` to its corresponding NUL-terminated encoding name.
+pub fn cjk_encoding_name(encoding: CjkEncoding) -> &'static [u8] {
+    match encoding {
+        CjkEncoding::Ms90RksjH => b"90ms-RKSJ-H\0",
+        CjkEncoding::Ms90RksjV => b"90ms-RKSJ-V\0",
+        CjkEncoding::Msp90RksjH => b"90msp-RKSJ-H\0",
+        CjkEncoding::EucH => b"EUC-H\0",
+        CjkEncoding::EucV => b"EUC-V\0",
+        CjkEncoding::GbEucH => b"GB-EUC-H\0",
+        CjkEncoding::GbEucV => b"GB-EUC-V\0",
+        CjkEncoding::GbkEucH => b"GBK-EUC-H\0",
+        CjkEncoding::GbkEucV => b"GBK-EUC-V\0",
+        CjkEncoding::EtenB5H => b"ETen-B5-H\0",
+        CjkEncoding::EtenB5V => b"ETen-B5-V\0",
+        CjkEncoding::KscEucH => b"KSC-EUC-H\0",
+        CjkEncoding::KscEucV => b"KSC-EUC-V\0",
+        CjkEncoding::KscmsUhcH => b"KSCms-UHC-H\0",
+        CjkEncoding::KscmsUhcHwH => b"KSCms-UHC-HW-H\0",
+        CjkEncoding::KscmsUhcHwV => b"KSCms-UHC-HW-V\0",
+    }
+}
+
+/// A built-in text encoding.
+///
+/// Apart from `Encoding::Utf8`, these are single-byte encodings that can represent at most 256
+/// characters, but keep text compact and can be used with every kind of font.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    /// The standard Latin text encoding of Type 1 fonts.
+    Standard,
+    /// Windows code page 1252 (Western European).
+    WinAnsi,
+    /// The classic Mac OS Western European encoding.
+    MacRoman,
+    /// ISO 8859-2, Latin-2 (Central European).
+    Iso8859_2,
+    /// ISO 8859-3, Latin-3 (South European).
+    Iso8859_3,
+    /// ISO 8859-4, Latin-4 (North European).
+    Iso8859_4,
+    /// ISO 8859-5, Latin/Cyrillic.
+    Iso8859_5,
+    /// ISO 8859-6, Latin/Arabic.
+    Iso8859_6,
+    /// ISO 8859-7, Latin/Greek.
+    Iso8859_7,
+    /// ISO 8859-8, Latin/Hebrew.
+    Iso8859_8,
+    /// ISO 8859-9, Latin-5 (Turkish).
+    Iso8859_9,
+    /// ISO 8859-10, Latin-6 (Nordic).
+    Iso8859_10,
+    /// ISO 8859-11, Latin/Thai.
+    Iso8859_11,
+    /// ISO 8859-13, Latin-7 (Baltic).
+    Iso8859_13,
+    /// ISO 8859-14, Latin-8 (Celtic).
+    Iso8859_14,
+    /// ISO 8859-15, Latin-9 (Western European, with the euro sign).
+    Iso8859_15,
+    /// ISO 8859-16, Latin-10 (South-Eastern European).
+    Iso8859_16,
+    /// Windows code page 1250 (Central European).
+    Cp1250,
+    /// Windows code page 1251 (Cyrillic).
+    Cp1251,
+    /// Windows code page 1252 (Western European).
+    Cp1252,
+    /// Windows code page 1253 (Greek).
+    Cp1253,
+    /// Windows code page 1254 (Turkish).
+    Cp1254,
+    /// Windows code page 1255 (Hebrew).
+    Cp1255,
+    /// Windows code page 1256 (Arabic).
+    Cp1256,
+    /// Windows code page 1257 (Baltic).
+    Cp1257,
+    /// Windows code page 1258 (Vietnamese).
+    Cp1258,
+    /// The Russian KOI8-R encoding.
+    Koi8R,
+    /// UTF-8, which can encode every Unicode character. Only usable with TrueType fonts.
+    Utf8,
+}
+
+/// Converts an `Encoding` to its corresponding NUL-terminated encoding name.
+pub fn encoding_name(encoding: Encoding) -> &'static [u8] {
+    match encoding {
+        Encoding::Standard => b"StandardEncoding\0",
+        Encoding::WinAnsi => b"WinAnsiEncoding\0",
+        Encoding::MacRoman => b"MacRomanEncoding\0",
+        Encoding::Iso8859_2 => b"ISO8859-2\0",
+        Encoding::Iso8859_3 => b"ISO8859-3\0",
+        Encoding::Iso8859_4 => b"ISO8859-4\0",
+        Encoding::Iso8859_5 => b"ISO8859-5\0",
+        Encoding::Iso8859_6 => b"ISO8859-6\0",
+        Encoding::Iso8859_7 => b"ISO8859-7\0",
+        Encoding::Iso8859_8 => b"ISO8859-8\0",
+        Encoding::Iso8859_9 => b"ISO8859-9\0",
+        Encoding::Iso8859_10 => b"ISO8859-10\0",
+        Encoding::Iso8859_11 => b"ISO8859-11\0",
+        Encoding::Iso8859_13 => b"ISO8859-13\0",
+        Encoding::Iso8859_14 => b"ISO8859-14\0",
+        Encoding::Iso8859_15 => b"ISO8859-15\0",
+        Encoding::Iso8859_16 => b"ISO8859-16\0",
+        Encoding::Cp1250 => b"CP1250\0",
+        Encoding::Cp1251 => b"CP1251\0",
+        Encoding::Cp1252 => b"CP1252\0",
+        Encoding::Cp1253 => b"CP1253\0",
+        Encoding::Cp1254 => b"CP1254\0",
+        Encoding::Cp1255 => b"CP1255\0",
+        Encoding::Cp1256 => b"CP1256\0",
+        Encoding::Cp1257 => b"CP1257\0",
+        Encoding::Cp1258 => b"CP1258\0",
+        Encoding::Koi8R => b"KOI8-R\0",
+        Encoding::Utf8 => b"UTF-8\0",
+    }
+}
+
+/// Converts an encoding name to its corresponding `Encoding`, if it is one.
+pub fn encoding_from_name(name: &[u8]) -> Option<Encoding> {
+    match name {
+        b"StandardEncoding" => Some(Encoding::Standard),
+        b"WinAnsiEncoding" => Some(Encoding::WinAnsi),
+        b"MacRomanEncoding" => Some(Encoding::MacRoman),
+        b"ISO8859-2" => Some(Encoding::Iso8859_2),
+        b"ISO8859-3" => Some(Encoding::Iso8859_3),
+        b"ISO8859-4" => Some(Encoding::Iso8859_4),
+        b"ISO8859-5" => Some(Encoding::Iso8859_5),
+        b"ISO8859-6" => Some(Encoding::Iso8859_6),
+        b"ISO8859-7" => Some(Encoding::Iso8859_7),
+        b"ISO8859-8" => Some(Encoding::Iso8859_8),
+        b"ISO8859-9" => Some(Encoding::Iso8859_9),
+        b"ISO8859-10" => Some(Encoding::Iso8859_10),
+        b"ISO8859-11" => Some(Encoding::Iso8859_11),
+        b"ISO8859-13" => Some(Encoding::Iso8859_13),
+        b"ISO8859-14" => Some(Encoding::Iso8859_14),
+        b"ISO8859-15" => Some(Encoding::Iso8859_15),
+        b"ISO8859-16" => Some(Encoding::Iso8859_16),
+        b"CP1250" => Some(Encoding::Cp1250),
+        b"CP1251" => Some(Encoding::Cp1251),
+        b"CP1252" => Some(Encoding::Cp1252),
+        b"CP1253" => Some(Encoding::Cp1253),
+        b"CP1254" => Some(Encoding::Cp1254),
+        b"CP1255" => Some(Encoding::Cp1255),
+        b"CP1256" => Some(Encoding::Cp1256),
+        b"CP1257" => Some(Encoding::Cp1257),
+        b"CP1258" => Some(Encoding::Cp1258),
+        b"KOI8-R" => Some(Encoding::Koi8R),
+        b"UTF-8" => Some(Encoding::Utf8),
+        _ => None,
+    }
+}
+
+/// Options for loading TrueType fonts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TtfOptions {
+    /// Whether the font data is embedded in the document. If `false`, the font is only referenced
+    /// by name and must be installed wherever the document is viewed. Defaults to `true`.
+    pub embed: bool,
+    /// The encoding of text drawn with the font. Defaults to `Encoding::Utf8`.
+    pub encoding: Encoding,
+}
+
+impl Default for TtfOptions {
+    fn default() -> TtfOptions {
+        TtfOptions { embed: true, encoding: Encoding::Utf8 }
+    }
+}
+
+/// The result of measuring a piece of text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextWidth {
+    /// The total width of the text, in glyph space units (1/1000 of the font size).
+    pub width: u32,
+    /// The number of characters in the text.
+    pub char_count: u32,
+    /// The number of word boundaries (whitespace characters) in the text.
+    pub word_count: u32,
+    /// The number of space characters in the text.
+    pub space_count: u32,
+}
+
+/// Converts an internal text width measurement to a `TextWidth`.
+pub fn text_width_from_raw(width: haru::HPDF_TextWidth) -> TextWidth {
+    TextWidth {
+        width: width.width,
+        char_count: width.numchars,
+        word_count: width.numwords,
+        space_count: width.numspace,
+    }
+}
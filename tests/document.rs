@@ -3,7 +3,8 @@ extern crate libharu;
 #[macro_use]
 mod util;
 
-use libharu::{Document, Error, PageLayout};
+use libharu::{ColorSpace, CompressionMode, DateTime, Document, Encoding, EncryptionMode, Error,
+              InfoKey, PageLayout, PageNumberStyle, Permissions, Point, Size, TtfOptions};
 use std::fs::File;
 use std::io;
 use util::*;
@@ -20,6 +21,99 @@ fn set_pages_configuration() {
     assert_pdf("document_set_pages_configuration", &mut document);
 }
 
+#[test]
+fn set_password() {
+    let mut document = Document::new().unwrap();
+    document.set_password("owner", "1234").unwrap()
+        .set_permission(Permissions { print: true, ..Permissions::read_only() }).unwrap()
+        .set_encryption_mode(EncryptionMode::Rc4Key128).unwrap();
+    document.add_page().unwrap();
+    assert_pdf_contains(&mut document, b"/Encrypt");
+}
+
+#[test]
+fn set_password_errors() {
+    let mut document = Document::new().unwrap();
+    expect_error!(document.set_password("", "1234"), Error::EncryptInvalidPassword);
+    expect_error!(document.set_password("same", "same"), Error::EncryptInvalidPassword);
+    expect_error!(document.set_password("own\0er", "1234"), Error::StringWithInternalNul);
+    expect_error!(document.set_password("owner", "12\034"), Error::StringWithInternalNul);
+}
+
+#[test]
+fn encryption_without_password() {
+    let mut document = Document::new().unwrap();
+    expect_error!(document.set_permission(Permissions::all()),
+                  Error::DocumentEncryptionDictionaryNotFound);
+    expect_error!(document.set_encryption_mode(EncryptionMode::Rc4Key40),
+                  Error::DocumentEncryptionDictionaryNotFound);
+}
+
+#[test]
+fn info() {
+    let mut document = Document::new().unwrap();
+    assert_eq!(document.info(InfoKey::Title), None);
+    document.set_info(InfoKey::Title, "Monthly Report").unwrap()
+        .set_info(InfoKey::Author, "Accounting").unwrap();
+    assert_eq!(document.info(InfoKey::Title), Some("Monthly Report".to_string()));
+    assert_eq!(document.info(InfoKey::Author), Some("Accounting".to_string()));
+    assert_eq!(document.info(InfoKey::Subject), None);
+    expect_error!(document.set_info(InfoKey::Keywords, "a\0b"), Error::StringWithInternalNul);
+}
+
+#[test]
+fn info_without_current_encoder() {
+    let mut document = Document::new().unwrap();
+    document.set_info(InfoKey::Title, "Überblick").unwrap();
+    assert_eq!(document.info(InfoKey::Title), Some("Überblick".to_string()));
+    // The value is written as UTF-16 with a byte order mark.
+    assert_pdf_contains(&mut document, b"<FEFF00DC");
+}
+
+#[test]
+fn current_encoder() {
+    let mut document = Document::new().unwrap();
+    document.set_current_encoder(Encoding::WinAnsi).unwrap();
+    assert_eq!(document.current_encoder(), Some(Encoding::WinAnsi));
+    document.set_info(InfoKey::Title, "Café").unwrap();
+    assert_eq!(document.info(InfoKey::Title), Some("Café".to_string()));
+    expect_error!(document.set_info(InfoKey::Title, "Ωmega"), Error::UnencodableCharacter('Ω'));
+}
+
+#[test]
+fn creation_date() {
+    let mut document = Document::new().unwrap();
+    assert_eq!(document.creation_date(), None);
+    let date = DateTime::new(2016, 2, 29, 13, 45, 30).unwrap();
+    document.set_creation_date(date).unwrap();
+    assert_eq!(document.creation_date(), Some(date));
+    let date = date.with_utc_offset(-330).unwrap();
+    document.set_creation_date(date).unwrap();
+    assert_eq!(document.creation_date(), Some(date));
+}
+
+#[test]
+fn modification_date() {
+    let mut document = Document::new().unwrap();
+    assert_eq!(document.modification_date(), None);
+    let date = DateTime::new(1999, 12, 31, 23, 59, 59).unwrap().with_utc_offset(0).unwrap();
+    document.set_modification_date(date).unwrap();
+    assert_eq!(document.modification_date(), Some(date));
+}
+
+#[test]
+fn invalid_date_time() {
+    expect_error!(DateTime::new(2015, 2, 29, 0, 0, 0), Error::InvalidDateTime);
+    expect_error!(DateTime::new(2016, 13, 1, 0, 0, 0), Error::InvalidDateTime);
+    expect_error!(DateTime::new(2016, 1, 1, 24, 0, 0), Error::InvalidDateTime);
+    let date = DateTime::new(2016, 1, 1, 0, 0, 0).unwrap();
+    expect_error!(date.with_utc_offset(24 * 60), Error::InvalidDateTime);
+
+    let mut document = Document::new().unwrap();
+    let date = DateTime { day: 32, ..date };
+    expect_error!(document.set_creation_date(date), Error::InvalidDateTime);
+}
+
 #[test]
 fn page_layout() {
     let mut document = Document::new().unwrap();
@@ -35,6 +129,37 @@ fn set_page_layout() {
     assert_pdf("document_set_page_layout", &mut document);
 }
 
+#[test]
+fn compression_mode() {
+    let mut document = Document::new().unwrap();
+    assert_eq!(document.compression_mode(), CompressionMode::none());
+    document.set_compression_mode(CompressionMode::all()).unwrap();
+    assert_eq!(document.compression_mode(), CompressionMode::all());
+}
+
+#[test]
+fn set_compression_mode() {
+    fn save_with(mode: CompressionMode) -> usize {
+        let mut document = Document::new().unwrap();
+        document.set_compression_mode(mode).unwrap();
+        let mut page = document.add_page().unwrap();
+        for i in 0..100 {
+            page.rectangle(Point::new(i as f32, i as f32), Size::new(10.0, 10.0)).unwrap();
+        }
+        page.stroke().unwrap();
+        let mut data = vec![];
+        document.save(&mut data).unwrap();
+        data.len()
+    }
+
+    let uncompressed = save_with(CompressionMode::none());
+    let text = CompressionMode { text: true, ..CompressionMode::none() };
+    assert!(save_with(text) < uncompressed);
+    assert!(save_with(CompressionMode::all()) < uncompressed);
+    assert_eq!(save_with(CompressionMode { image: true, ..CompressionMode::none() }),
+               uncompressed);
+}
+
 #[test]
 fn add_page() {
     let mut document = Document::new().unwrap();
@@ -50,11 +175,172 @@ fn insert_page() {
     assert_pdf("document_insert_page", &mut document);
 }
 
+#[test]
+fn create_outline() {
+    let mut document = Document::new().unwrap();
+    let mut page = document.add_page().unwrap();
+    let mut destination = page.create_destination().unwrap();
+    destination.set_xyz(0.0, page.height(), 1.0).unwrap();
+
+    let mut root = document.create_outline(None, "Chapter 1", None).unwrap();
+    root.set_opened(true).unwrap().set_destination(&destination).unwrap();
+    let mut child = document.create_outline(Some(&root), "1.1 Überblick", None).unwrap();
+    child.set_destination(&destination).unwrap();
+    document.create_outline(Some(&root), "1.2 Café", Some(Encoding::WinAnsi)).unwrap();
+    expect_error!(document.create_outline(Some(&root), "Ωmega", Some(Encoding::WinAnsi)),
+                  Error::UnencodableCharacter('Ω'));
+    expect_error!(document.create_outline(Some(&root), "\0", None), Error::StringWithInternalNul);
+
+    assert_pdf_contains(&mut document, b"/Outlines");
+    // Without a current encoding, "1.1 Überblick" is written as UTF-16 with a byte order mark.
+    assert_pdf_contains(&mut document, b"<FEFF0031002E0031002000DC");
+}
+
+#[test]
+fn add_page_label() {
+    let mut document = Document::new().unwrap();
+    for _ in 0..6 {
+        document.add_page().unwrap();
+    }
+
+    document.add_page_label(0, PageNumberStyle::LowerRoman, 1, None).unwrap()
+        .add_page_label(2, PageNumberStyle::Decimal, 1, None).unwrap()
+        .add_page_label(4, PageNumberStyle::Decimal, 7, Some("A-")).unwrap();
+    expect_error!(document.add_page_label(5, PageNumberStyle::Decimal, 1, Some("\0")),
+                  Error::StringWithInternalNul);
+
+    assert_pdf_contains(&mut document, b"/PageLabels");
+    assert_pdf_contains(&mut document, b"/S /r");
+    assert_pdf_contains(&mut document, b"/S /D");
+    assert_pdf_contains(&mut document, b"/St 7");
+    assert_pdf_contains(&mut document, b"/P (A-)");
+}
+
 #[test]
 fn load_ttf_font() {
     let mut document = Document::new().unwrap();
     let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
-    assert!(document.load_ttf_font(file).is_ok());
+    assert!(document.load_ttf_font(file, TtfOptions::default()).is_ok());
+}
+
+#[test]
+fn load_ttf_font_without_embedding() {
+    fn save_with(options: TtfOptions) -> usize {
+        let mut document = Document::new().unwrap();
+        let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+        let font = document.load_ttf_font(file, options).unwrap();
+        document.add_page().unwrap()
+            .set_font_and_size(&font, 12.0).unwrap()
+            .begin_text().unwrap()
+            .text_out("Test text!", Point::new(10.0, 10.0)).unwrap()
+            .end_text().unwrap();
+        let mut data = vec![];
+        document.save(&mut data).unwrap();
+        data.len()
+    }
+
+    assert!(save_with(TtfOptions { embed: false, ..TtfOptions::default() }) <
+            save_with(TtfOptions::default()));
+}
+
+#[test]
+fn load_ttc_font() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("ttf/gohufont-11.ttc")).unwrap();
+    let font = document.load_ttc_font(file, 1, TtfOptions::default()).unwrap();
+    assert_eq!(font.encoding_name(), "UTF-8");
+}
+
+#[test]
+fn load_ttc_font_errors() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("ttf/gohufont-11.ttc")).unwrap();
+    expect_error!(document.load_ttc_font(file, 2, TtfOptions::default()), Error::InvalidTtcIndex);
+    let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+    expect_error!(document.load_ttc_font(file, 0, TtfOptions::default()), Error::InvalidTtcFile);
+}
+
+#[test]
+fn load_type1_font() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("afm/boxes.afm")).unwrap();
+    let font = document.load_type1_font(file, None::<File>).unwrap();
+    assert_eq!(font.name(), "Boxes-Regular");
+    assert_eq!(font.unicode_width('A'), 600);
+    assert_eq!((font.ascent(), font.descent(), font.cap_height()), (800, -200, 700));
+}
+
+#[test]
+fn load_type1_font_errors() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+    expect_error!(document.load_type1_font(file, None::<File>), Error::InvalidAfmHeader);
+
+    let afm = "StartFontMetrics 4.1\nFontName Broken\nStartCharMetrics 1\nX 65 ; WX 600 ; N A ;\n\
+               EndCharMetrics\nEndFontMetrics\n";
+    expect_error!(document.load_type1_font(io::Cursor::new(afm.as_bytes()), None::<File>),
+                  Error::InvalidCharMatricsData);
+
+    let afm = File::open(fixture_path("afm/boxes.afm")).unwrap();
+    let pfb = io::Cursor::new(vec![0u8; 64]);
+    expect_error!(document.load_type1_font(afm, Some(pfb)), Error::UnsupportedType1Font);
+}
+
+#[test]
+fn load_png_image() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("png/gradient.png")).unwrap();
+    let image = document.load_png_image(file).unwrap();
+    assert_eq!(image.size(), Size::new(16.0, 16.0));
+    assert_eq!(image.bits_per_component(), 8);
+    assert_eq!(image.color_space().unwrap(), ColorSpace::DeviceRgb);
+}
+
+#[test]
+fn load_jpeg_image() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("jpeg/logo.jpg")).unwrap();
+    let image = document.load_jpeg_image(file).unwrap();
+    assert_eq!((image.width(), image.height()), (16, 16));
+    assert_eq!(image.bits_per_component(), 8);
+    assert_eq!(image.color_space().unwrap(), ColorSpace::DeviceRgb);
+}
+
+#[test]
+fn load_invalid_image() {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+    expect_error!(document.load_jpeg_image(file), Error::InvalidJpegData);
+}
+
+#[test]
+fn load_raw_image() {
+    let mut document = Document::new().unwrap();
+    let data = [0xff, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff];
+    let image = document.load_raw_image(&data, 2, 2, ColorSpace::DeviceRgb, 8).unwrap();
+    assert_eq!((image.width(), image.height()), (2, 2));
+    assert_eq!(image.bits_per_component(), 8);
+    assert_eq!(image.color_space().unwrap(), ColorSpace::DeviceRgb);
+
+    let data = [0b10000000, 0b01000000];
+    let image = document.load_raw_image(&data, 2, 2, ColorSpace::DeviceGray, 1).unwrap();
+    assert_eq!(image.color_space().unwrap(), ColorSpace::DeviceGray);
+}
+
+#[test]
+fn load_raw_image_errors() {
+    let mut document = Document::new().unwrap();
+    let data = [0; 16];
+    expect_error!(document.load_raw_image(&data, 2, 2, ColorSpace::Lab, 8),
+                  Error::InvalidColorSpace);
+    expect_error!(document.load_raw_image(&data, 2, 2, ColorSpace::DeviceGray, 3),
+                  Error::InvalidImage);
+    expect_error!(document.load_raw_image(&data, 0, 2, ColorSpace::DeviceGray, 8),
+                  Error::InvalidImage);
+    expect_error!(document.load_raw_image(&data[..15], 2, 2, ColorSpace::DeviceCmyk, 8),
+                  Error::InvalidImage);
+    expect_error!(document.load_raw_image(&[0], 2, 2, ColorSpace::DeviceGray, 1),
+                  Error::InvalidImage);
 }
 
 #[test]
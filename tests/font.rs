@@ -0,0 +1,167 @@
+extern crate libharu;
+
+#[macro_use]
+mod util;
+
+use libharu::*;
+use std::fs::File;
+use util::*;
+
+#[test]
+fn name() {
+    with_font(|font| {
+        assert!(!font.name().is_empty());
+        assert_eq!(font.encoding_name(), "UTF-8");
+    });
+}
+
+#[test]
+fn vertical_metrics() {
+    with_font(|font| {
+        assert!(font.ascent() > 0);
+        assert!(font.descent() <= 0);
+        assert!(font.cap_height() as i32 <= font.ascent());
+        assert!(font.x_height() <= font.cap_height());
+        let (lower_left, upper_right) = font.bbox();
+        assert!(lower_left.x < upper_right.x && lower_left.y < upper_right.y);
+    });
+}
+
+#[test]
+fn unicode_width() {
+    with_font(|font| {
+        // Gohufont is monospaced.
+        assert!(font.unicode_width('a') > 0);
+        assert_eq!(font.unicode_width('a'), font.unicode_width('W'));
+        assert_eq!(font.unicode_width('\u{1f600}'), 0);
+    });
+}
+
+#[test]
+fn text_width() {
+    with_font(|font| {
+        let width = font.text_width("Test text, too!").unwrap();
+        assert_eq!(width.width, 15 * font.unicode_width('a') as u32);
+        assert_eq!(width.char_count, 15);
+        assert_eq!(width.space_count, 2);
+    });
+}
+
+#[test]
+fn has_glyph() {
+    with_font(|font| {
+        assert!(font.has_glyph('a'));
+        assert!(!font.has_glyph('漢'));
+        assert!(!font.has_glyph('\u{1f600}'));
+    });
+}
+
+#[test]
+fn missing_glyphs() {
+    with_font(|font| {
+        assert!(font.missing_glyphs("Test text!").is_empty());
+        assert_eq!(font.missing_glyphs("a \u{1f600}漢\u{1f600}b"), vec!['\u{1f600}', '漢']);
+    });
+
+    let mut document = Document::new().unwrap();
+    let font = document.font(StandardFont::Helvetica, Some(Encoding::WinAnsi)).unwrap();
+    assert_eq!(font.missing_glyphs("Café Ωmega"), vec!['Ω']);
+    document.use_cjk(CjkLanguage::Japanese).unwrap();
+    let font = document.cjk_font(CjkFont::MsGothic, CjkEncoding::Ms90RksjH).unwrap();
+    assert_eq!(font.missing_glyphs("日本\u{1f600}"), vec!['\u{1f600}']);
+}
+
+#[test]
+fn standard_font() {
+    let mut document = Document::new().unwrap();
+    let font = document.font(StandardFont::Helvetica, None).unwrap();
+    assert_eq!(font.name(), "Helvetica");
+    assert_eq!(font.encoding_name(), "StandardEncoding");
+    let font = document.font(StandardFont::TimesBoldItalic, Some(Encoding::WinAnsi)).unwrap();
+    assert_eq!(font.name(), "Times-BoldItalic");
+    assert_eq!(font.encoding_name(), "WinAnsiEncoding");
+    assert_eq!(document.font(StandardFont::ZapfDingbats, None).unwrap().name(), "ZapfDingbats");
+    assert!(document.font(StandardFont::Courier, None).unwrap().unicode_width('W') > 0);
+}
+
+#[test]
+fn standard_font_errors() {
+    let mut document = Document::new().unwrap();
+    expect_error!(document.font(StandardFont::Helvetica, Some(Encoding::Utf8)),
+                  Error::InvalidEncoderType);
+}
+
+#[test]
+fn single_byte_encoding() {
+    let mut document = Document::new().unwrap();
+    let font = document.font(StandardFont::Helvetica, Some(Encoding::WinAnsi)).unwrap();
+    assert_eq!(font.encoding(), Some(Encoding::WinAnsi));
+    let width = font.text_width("Café €5").unwrap();
+    assert_eq!(width.char_count, 7);
+    let sum = "Café €5".chars().map(|c| font.unicode_width(c) as u32).sum::<u32>();
+    assert_eq!(width.width, sum);
+    expect_error!(font.text_width("Ωmega"), Error::UnencodableCharacter('Ω'));
+
+    let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+    let options = TtfOptions { encoding: Encoding::Cp1251, ..TtfOptions::default() };
+    let font = document.load_ttf_font(file, options).unwrap();
+    assert_eq!(font.encoding_name(), "CP1251");
+    assert_eq!(font.text_width("Привет").unwrap().char_count, 6);
+}
+
+#[test]
+fn cjk_font() {
+    let mut document = Document::new().unwrap();
+    expect_error!(document.cjk_font(CjkFont::MsMincho, CjkEncoding::Ms90RksjH),
+                  Error::InvalidFontName);
+    document.use_cjk(CjkLanguage::Japanese).unwrap()
+        .use_cjk(CjkLanguage::Korean).unwrap();
+    expect_error!(document.use_cjk(CjkLanguage::Japanese), Error::DuplicateRegistration);
+
+    let font = document.cjk_font(CjkFont::MsMincho, CjkEncoding::Ms90RksjH).unwrap();
+    assert_eq!(font.name(), "MS-Mincho");
+    assert_eq!(font.encoding_name(), "90ms-RKSJ-H");
+    let font = document.cjk_font(CjkFont::BatangChe, CjkEncoding::KscmsUhcH).unwrap();
+    assert_eq!(font.encoding_name(), "KSCms-UHC-H");
+    expect_error!(document.cjk_font(CjkFont::SimSun, CjkEncoding::GbkEucH),
+                  Error::InvalidFontName);
+}
+
+#[test]
+fn cjk_encoding() {
+    let mut document = Document::new().unwrap();
+    document.use_cjk(CjkLanguage::Japanese).unwrap();
+    let font = document.cjk_font(CjkFont::MsGothic, CjkEncoding::Ms90RksjH).unwrap();
+    assert_eq!(font.encoding(), None);
+    let width = font.text_width("日本 text").unwrap();
+    assert_eq!(width.char_count, 7);
+    assert_eq!(width.space_count, 1);
+    expect_error!(font.text_width("Ωmega \u{1f600}"), Error::UnencodableCharacter('\u{1f600}'));
+}
+
+#[test]
+fn add_line_head_codes() {
+    let mut document = Document::new().unwrap();
+    document.use_cjk(CjkLanguage::Japanese).unwrap();
+    document.add_line_head_codes(CjkEncoding::Ms90RksjH, &[0x8148, 0x8149]).unwrap();
+    expect_error!(document.add_line_head_codes(CjkEncoding::Ms90RksjH, &[0x8148, 0]),
+                  Error::InvalidParameter);
+    let codes: Vec<u16> = (0x8800..0x8900).collect();
+    expect_error!(document.add_line_head_codes(CjkEncoding::Ms90RksjH, &codes),
+                  Error::JwwCodeNumberLimitExceeded(_));
+
+    // The failed call added nothing, so the remaining capacity can still be filled.
+    let free = (0..codes.len()).rev().find(|&n| {
+        document.add_line_head_codes(CjkEncoding::Ms90RksjH, &codes[..n]).is_ok()
+    }).unwrap();
+    assert!(free > 0);
+    expect_error!(document.add_line_head_codes(CjkEncoding::Ms90RksjH, &codes[free..free + 1]),
+                  Error::JwwCodeNumberLimitExceeded(0));
+}
+
+#[inline]
+fn with_font<F: FnOnce(&Font)>(f: F) {
+    let mut document = Document::new().unwrap();
+    let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
+    f(&document.load_ttf_font(file, TtfOptions::default()).unwrap());
+}
@@ -0,0 +1,46 @@
+extern crate libharu;
+
+use libharu::{Matrix, Point};
+
+#[test]
+fn identity() {
+    let point = Point::new(3.0, 4.0);
+    assert_eq!(Matrix::identity().transform_point(point), point);
+    assert_eq!(Matrix::default(), Matrix::identity());
+}
+
+#[test]
+fn transform_point() {
+    let point = Point::new(3.0, 4.0);
+    assert_eq!(Matrix::translation(10.0, 20.0).transform_point(point), Point::new(13.0, 24.0));
+    assert_eq!(Matrix::scaling(2.0, -1.0).transform_point(point), Point::new(6.0, -4.0));
+    assert_point_eq(Matrix::rotation(90.0).transform_point(point), Point::new(-4.0, 3.0));
+    assert_point_eq(Matrix::skew(45.0, 0.0).transform_point(point), Point::new(3.0, 7.0));
+}
+
+#[test]
+fn multiply() {
+    let point = Point::new(3.0, 4.0);
+    // Scale, then translate.
+    let matrix = Matrix::scaling(2.0, 2.0) * Matrix::translation(10.0, 0.0);
+    assert_eq!(matrix.transform_point(point), Point::new(16.0, 8.0));
+    // Translate, then scale.
+    let matrix = Matrix::translation(10.0, 0.0) * Matrix::scaling(2.0, 2.0);
+    assert_eq!(matrix.transform_point(point), Point::new(26.0, 8.0));
+    assert_eq!(matrix * Matrix::identity(), matrix);
+}
+
+#[test]
+fn invert() {
+    let point = Point::new(3.0, 4.0);
+    let matrix = Matrix::rotation(30.0) * Matrix::scaling(2.0, 3.0) * Matrix::translation(5.0, 6.0);
+    let inverse = matrix.invert().unwrap();
+    assert_point_eq(inverse.transform_point(matrix.transform_point(point)), point);
+    assert_eq!(Matrix::scaling(0.0, 1.0).invert(), None);
+}
+
+fn assert_point_eq(actual: Point, expected: Point) {
+    if (actual.x - expected.x).abs() > 1e-4 || (actual.y - expected.y).abs() > 1e-4 {
+        panic!("{:?} is not approximately {:?}", actual, expected);
+    }
+}
@@ -1,5 +1,6 @@
 extern crate libharu;
 
+#[macro_use]
 mod util;
 
 use libharu::*;
@@ -26,6 +27,112 @@ fn height() {
     });
 }
 
+#[test]
+fn set_size() {
+    with_page(|_, page| {
+        page.set_size(PaperSize::Letter, Orientation::Portrait).unwrap();
+        assert_eq!((page.width(), page.height()), (612.0, 792.0));
+        page.set_size(PaperSize::Letter, Orientation::Landscape).unwrap();
+        assert_eq!((page.width(), page.height()), (792.0, 612.0));
+        page.set_size(PaperSize::A4, Orientation::Landscape).unwrap();
+        assert!(page.width() > page.height());
+    });
+}
+
+#[test]
+fn set_rotate() {
+    with_page(|_, page| {
+        page.set_rotate(90).unwrap().set_rotate(270).unwrap().set_rotate(0).unwrap();
+        expect_error!(page.set_rotate(45), Error::PageInvalidRotateValue);
+    });
+}
+
+#[test]
+fn save_state() {
+    with_page(|_, page| {
+        assert_eq!(page.gstate_depth(), 1);
+        page.save_state().unwrap().set_line_width(5.0).unwrap();
+        assert_eq!(page.gstate_depth(), 2);
+        page.restore_state().unwrap();
+        assert_eq!(page.gstate_depth(), 1);
+        assert_eq!(page.line_width(), 1.0);
+        expect_error!(page.restore_state(), Error::PageCannotRestoreGstate);
+
+        for _ in 1..28 {
+            page.save_state().unwrap();
+        }
+        assert_eq!(page.gstate_depth(), 28);
+        expect_error!(page.save_state(), Error::GstateLimitExceeded);
+    });
+}
+
+#[test]
+fn with_saved_state() {
+    with_page(|_, page| {
+        let width = page.with_saved_state(|page| {
+            try!(page.set_line_width(5.0));
+            // Unbalanced saves are discarded as well.
+            try!(page.save_state());
+            Ok(page.line_width())
+        }).unwrap();
+        assert_eq!(width, 5.0);
+        assert_eq!(page.line_width(), 1.0);
+        assert_eq!(page.gstate_depth(), 1);
+
+        let result: Result<(), Error> = page.with_saved_state(|page| {
+            try!(page.set_gray_stroke(0.5));
+            Err(Error::InvalidParameter)
+        });
+        expect_error!(result, Error::InvalidParameter);
+        assert_eq!(page.gray_stroke(), 0.0);
+        assert_eq!(page.gstate_depth(), 1);
+    });
+}
+
+#[test]
+fn with_saved_state_in_text_mode() {
+    with_page(|document, page| {
+        let font = document.font(StandardFont::Helvetica, Some(Encoding::WinAnsi)).unwrap();
+        let result = page.with_saved_state(|page| {
+            try!(page.set_font_and_size(&font, 12.0));
+            try!(page.begin_text());
+            try!(page.show_text("\u{1f600}"));
+            page.end_text().map(|_| ())
+        });
+        expect_error!(result, Error::UnencodableCharacter('\u{1f600}'));
+        assert_eq!(page.gstate_depth(), 1);
+        assert_eq!(page.font_size(), None);
+    });
+}
+
+#[test]
+fn set_ext_gstate() {
+    with_page(|document, page| {
+        let mut ext_gstate = document.create_ext_gstate().unwrap();
+        ext_gstate.set_alpha_stroke(0.5).unwrap()
+            .set_alpha_fill(0.25).unwrap()
+            .set_blend_mode(BlendMode::Multiply).unwrap();
+        expect_error!(ext_gstate.set_alpha_fill(1.5), Error::ExtGstateOutOfRange);
+        page.set_ext_gstate(&ext_gstate).unwrap();
+        expect_error!(ext_gstate.set_alpha_fill(0.5), Error::ExtGstateReadOnly);
+        draw_rectangle_fill_stroke(page);
+    });
+}
+
+#[test]
+fn concat() {
+    with_page(|_, page| {
+        assert_eq!(page.transform_matrix(), Matrix::identity());
+        page.translate(100.0, 50.0).unwrap().scale(2.0, 2.0).unwrap();
+        let expected = Matrix::scaling(2.0, 2.0) * Matrix::translation(100.0, 50.0);
+        assert_eq!(page.transform_matrix(), expected);
+        page.concat(&expected.invert().unwrap()).unwrap();
+        assert_eq!(page.transform_matrix(), Matrix::identity());
+        page.rotate(90.0).unwrap().skew(10.0, 0.0).unwrap();
+        assert!(page.transform_matrix() != Matrix::identity());
+    });
+}
+
 #[test]
 fn stroke() {
     with_page(|document, page| {
@@ -52,6 +159,28 @@ fn eo_fill() {
     });
 }
 
+#[test]
+fn clip() {
+    with_page(|document, page| {
+        draw_overlapping_shape(page);
+        page.clip().unwrap().end_path().unwrap();
+        page.rectangle(Point::new(0.0, 0.0), Size::new(60.0, 60.0)).unwrap();
+        page.fill().unwrap();
+        assert_pdf("page_clip", document);
+    });
+}
+
+#[test]
+fn eo_clip() {
+    with_page(|document, page| {
+        draw_overlapping_shape(page);
+        page.eo_clip().unwrap().end_path().unwrap();
+        page.rectangle(Point::new(0.0, 0.0), Size::new(60.0, 60.0)).unwrap();
+        page.fill().unwrap();
+        assert_pdf("page_eo_clip", document);
+    });
+}
+
 #[test]
 fn fill_stroke() {
     with_page(|document, page| {
@@ -353,6 +482,40 @@ fn show_text() {
     });
 }
 
+#[test]
+fn font_specific_text() {
+    with_page(|document, page| {
+        let font = document.font(StandardFont::ZapfDingbats, None).unwrap();
+        assert!(font.missing_glyphs("a3").is_empty());
+        page.set_font_and_size(&font, 20.0).unwrap()
+            .begin_text().unwrap()
+            .text_out("a3", Point::new(10.0, 10.0)).unwrap()
+            .end_text().unwrap();
+
+        assert_pdf_contains(document, b"(a3) Tj");
+    });
+}
+
+#[test]
+fn multi_line_text_with_single_byte_encoding() {
+    with_page(|document, page| {
+        let font = document.font(StandardFont::Helvetica, Some(Encoding::WinAnsi)).unwrap();
+        assert!(font.missing_glyphs("a\nb").is_empty());
+        page.set_font_and_size(&font, 12.0).unwrap().set_text_leading(14.0).unwrap();
+
+        // The line ends at the line feed.
+        let (len, _) = page.measure_text("a\nb", 100.0, false).unwrap();
+        assert!(len > 0 && len < 3);
+
+        page.begin_text().unwrap();
+        let outcome = page.text_rect_with_outcome("a\nb", Point::new(10.0, 10.0),
+                                                  Size::new(100.0, 100.0), TextAlignment::Left)
+            .unwrap();
+        assert_eq!(outcome, TextRectOutcome { rendered_bytes: 3, overflowed: false });
+        page.end_text().unwrap();
+    });
+}
+
 #[test]
 fn text_rect() {
     with_page(|document, page| {
@@ -366,6 +529,115 @@ fn text_rect() {
     });
 }
 
+#[test]
+fn text_rect_with_outcome() {
+    with_page(|document, page| {
+        page.set_font_and_size(&load_font(document), 8.0).unwrap()
+            .set_text_leading(10.0).unwrap()
+            .begin_text().unwrap();
+
+        let outcome = page.text_rect_with_outcome(LOREM_IPSUM, Point::new(10.0, 10.0),
+                                                  Size::new(100.0, 30.0), TextAlignment::Left)
+            .unwrap();
+        assert!(outcome.overflowed);
+        assert!(outcome.rendered_bytes > 0 && outcome.rendered_bytes < LOREM_IPSUM.len());
+        let rest = &LOREM_IPSUM[outcome.rendered_bytes..];
+
+        let outcome = page.text_rect_with_outcome(rest, Point::new(10.0, 100.0),
+                                                  Size::new(500.0, 500.0), TextAlignment::Left)
+            .unwrap();
+        assert_eq!(outcome, TextRectOutcome { rendered_bytes: rest.len(), overflowed: false });
+        expect_error!(page.text_rect_with_outcome("\0", Point::new(10.0, 10.0),
+                                                  Size::new(100.0, 30.0), TextAlignment::Left),
+                      Error::StringWithInternalNul);
+        page.end_text().unwrap();
+    });
+}
+
+#[test]
+fn create_destination() {
+    with_page(|_, page| {
+        let mut destination = page.create_destination().unwrap();
+        destination.set_xyz(10.0, 500.0, 2.0).unwrap()
+            .set_fit().unwrap()
+            .set_fit_h(500.0).unwrap()
+            .set_fit_v(10.0).unwrap()
+            .set_fit_r(Point::new(10.0, 10.0), Size::new(100.0, 100.0)).unwrap()
+            .set_fit_b().unwrap()
+            .set_fit_bh(500.0).unwrap()
+            .set_fit_bv(10.0).unwrap();
+        expect_error!(destination.set_xyz(10.0, 500.0, 100.0), Error::InvalidParameter);
+    });
+}
+
+#[test]
+fn create_link_annotation() {
+    with_page(|_, page| {
+        let destination = page.create_destination().unwrap();
+        let (point, size) = (Point::new(10.0, 10.0), Size::new(100.0, 20.0));
+        let mut annotation = page.create_link_annotation(point, size, &destination).unwrap();
+        annotation.set_highlight_mode(HighlightMode::InvertBorder).unwrap()
+            .set_border_style(BorderStyle::Beveled, 1.0).unwrap()
+            .set_border_dash(2.0, 3, 1).unwrap()
+            .set_border_style(BorderStyle::Dashed { on: 3, off: 2, phase: 1 }, 1.0).unwrap();
+    });
+}
+
+#[test]
+fn create_uri_annotation() {
+    with_page(|_, page| {
+        let (point, size) = (Point::new(10.0, 10.0), Size::new(100.0, 20.0));
+        let mut annotation = page.create_uri_annotation(point, size, "http://libharu.org/")
+            .unwrap();
+        annotation.set_highlight_mode(HighlightMode::None).unwrap()
+            .set_border_style(BorderStyle::Solid, 0.0).unwrap();
+        expect_error!(page.create_uri_annotation(point, size, ""), Error::InvalidUri);
+    });
+}
+
+#[test]
+fn create_text_annotation() {
+    with_page(|_, page| {
+        let (point, size) = (Point::new(10.0, 10.0), Size::new(20.0, 20.0));
+        let mut annotation = page.create_text_annotation(point, size, "Check totals").unwrap();
+        annotation.set_icon(AnnotationIcon::Help).unwrap()
+            .set_opened(true).unwrap()
+            .set_rgb_color(1.0, 0.8, 0.0).unwrap()
+            .set_opacity(0.5).unwrap()
+            .set_author("QA").unwrap()
+            .set_subject("Totals").unwrap();
+        expect_error!(annotation.set_opacity(1.5), Error::InvalidParameter);
+        expect_error!(page.create_text_annotation(point, size, "\0"),
+                      Error::StringWithInternalNul);
+    });
+}
+
+#[test]
+fn create_markup_annotation() {
+    with_page(|_, page| {
+        let (point, size) = (Point::new(10.0, 10.0), Size::new(100.0, 20.0));
+        for &kind in &[MarkupKind::Highlight, MarkupKind::Underline, MarkupKind::Squiggly,
+                       MarkupKind::StrikeOut, MarkupKind::FreeText, MarkupKind::Square,
+                       MarkupKind::Circle] {
+            let mut annotation = page.create_markup_annotation(kind, point, size, "Note").unwrap();
+            annotation.set_rgb_color(1.0, 1.0, 0.0).unwrap().set_author("QA").unwrap();
+            expect_error!(annotation.set_icon(AnnotationIcon::Note), Error::InvalidAnnotation);
+        }
+
+        let (start, end) = (Point::new(10.0, 10.0), Point::new(100.0, 100.0));
+        page.create_line_annotation(start, end, "Note").unwrap();
+    });
+}
+
+#[test]
+fn draw_image() {
+    with_page(|document, page| {
+        let file = File::open(fixture_path("png/gradient.png")).unwrap();
+        let image = document.load_png_image(file).unwrap();
+        assert!(page.draw_image(&image, Point::new(10.0, 10.0), Size::new(64.0, 64.0)).is_ok());
+    });
+}
+
 #[test]
 fn set_font_and_size() {
     with_page(|document, page| {
@@ -377,6 +649,133 @@ fn set_font_and_size() {
     });
 }
 
+#[test]
+fn char_space() {
+    with_page(|_, page| {
+        assert_eq!(page.char_space(), 0.0);
+        page.set_char_space(2.5).unwrap();
+        assert_eq!(page.char_space(), 2.5);
+        assert!(page.set_char_space(500.0).is_err());
+    });
+}
+
+#[test]
+fn word_space() {
+    with_page(|_, page| {
+        assert_eq!(page.word_space(), 0.0);
+        page.set_word_space(-2.0).unwrap();
+        assert_eq!(page.word_space(), -2.0);
+        assert!(page.set_word_space(-50.0).is_err());
+    });
+}
+
+#[test]
+fn horizontal_scaling() {
+    with_page(|_, page| {
+        assert_eq!(page.horizontal_scaling(), 100.0);
+        page.set_horizontal_scaling(50.0).unwrap();
+        assert_eq!(page.horizontal_scaling(), 50.0);
+        assert!(page.set_horizontal_scaling(5.0).is_err());
+    });
+}
+
+#[test]
+fn text_rise() {
+    with_page(|_, page| {
+        assert_eq!(page.text_rise(), 0.0);
+        page.set_text_rise(4.0).unwrap();
+        assert_eq!(page.text_rise(), 4.0);
+    });
+}
+
+#[test]
+fn text_rendering_mode() {
+    with_page(|_, page| {
+        assert_eq!(page.text_rendering_mode(), TextRenderingMode::Fill);
+        page.set_text_rendering_mode(TextRenderingMode::Invisible).unwrap();
+        assert_eq!(page.text_rendering_mode(), TextRenderingMode::Invisible);
+        page.set_text_rendering_mode(TextRenderingMode::FillStrokeClip).unwrap();
+        assert_eq!(page.text_rendering_mode(), TextRenderingMode::FillStrokeClip);
+    });
+}
+
+#[test]
+fn text_matrix() {
+    with_page(|document, page| {
+        let matrix = Matrix::skew(15.0, 0.0) * Matrix::translation(10.0, 20.0);
+        expect_error!(page.set_text_matrix(&matrix), Error::PageInvalidGmode);
+        page.set_font_and_size(&load_font(document), 20.0).unwrap().begin_text().unwrap();
+        assert_eq!(page.text_matrix(), Matrix::identity());
+        page.set_text_matrix(&matrix).unwrap();
+        assert_eq!(page.text_matrix(), matrix);
+        assert_eq!(page.text_position(), Point::new(10.0, 20.0));
+    });
+}
+
+#[test]
+fn text_out_rotated() {
+    with_page(|document, page| {
+        page.set_font_and_size(&load_font(document), 20.0).unwrap()
+            .begin_text().unwrap()
+            .text_out_rotated("Test text!", Point::new(50.0, 10.0), 90.0).unwrap();
+        let expected = Matrix::rotation(90.0) * Matrix::translation(50.0, 10.0);
+        let matrix = page.text_matrix();
+        assert_eq!((matrix.a, matrix.b, matrix.c, matrix.d), (expected.a, expected.b, expected.c,
+                                                              expected.d));
+        // The text is drawn upward, so the text position only moves along the y-axis.
+        let position = page.text_position();
+        assert!((position.x - 50.0).abs() < 1e-3 && position.y > 10.0);
+        page.end_text().unwrap();
+    });
+}
+
+#[test]
+fn text_width() {
+    with_page(|document, page| {
+        expect_error!(page.text_width("Test text!"), Error::PageFontNotFound);
+        page.set_font_and_size(&load_font(document), 20.0).unwrap();
+        let width = page.text_width("Test text!").unwrap();
+        page.set_char_space(1.0).unwrap();
+        assert_eq!(page.text_width("Test text!").unwrap(), width + 10.0);
+    });
+}
+
+#[test]
+fn measure_text() {
+    with_page(|document, page| {
+        expect_error!(page.measure_text("Test text!", 100.0, false), Error::PageFontNotFound);
+        page.set_font_and_size(&load_font(document), 20.0).unwrap();
+        let width = page.text_width("Test text!").unwrap();
+        let (len, real_width) = page.measure_text("Test text!", width + 1.0, true).unwrap();
+        assert_eq!(len, 10);
+        assert!((real_width - width).abs() < 0.01);
+
+        let max_width = page.text_width("Test te").unwrap() + 1.0;
+        let (len, real_width) = page.measure_text("Test text!", max_width, false).unwrap();
+        assert_eq!(len, 7);
+        assert!((real_width - page.text_width("Test te").unwrap()).abs() < 0.01);
+        let (len, real_width) = page.measure_text("Test text!", max_width, true).unwrap();
+        assert_eq!(len, 5);
+        assert!((real_width - page.text_width("Test").unwrap()).abs() < 0.01);
+        assert_eq!(page.measure_text("Test text!", 1.0, true).unwrap().0, 0);
+    });
+}
+
+#[test]
+fn encoded_text() {
+    with_page(|document, page| {
+        let font = document.font(StandardFont::Helvetica, Some(Encoding::WinAnsi)).unwrap();
+        page.set_font_and_size(&font, 12.0).unwrap()
+            .begin_text().unwrap()
+            .text_out("Café", Point::new(10.0, 10.0)).unwrap();
+        expect_error!(page.show_text("日本"), Error::UnencodableCharacter('日'));
+
+        let max_width = page.text_width("é ").unwrap() + 1.0;
+        assert_eq!(page.measure_text("é é é", max_width, true).unwrap().0, "é ".len());
+        page.end_text().unwrap();
+    });
+}
+
 #[test]
 fn position() {
     with_page(|_, page| {
@@ -462,7 +861,7 @@ fn draw_overlapping_shape(page: &mut Page) {
 #[inline]
 fn load_font(document: &mut Document) -> Font {
     let file = File::open(fixture_path("ttf/gohufont-11.ttf")).unwrap();
-    document.load_ttf_font(file).unwrap()
+    document.load_ttf_font(file, TtfOptions::default()).unwrap()
 }
 
 static LOREM_IPSUM: &'static str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. \
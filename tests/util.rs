@@ -39,6 +39,15 @@ pub fn assert_pdf(name: &str, document: &mut Document) {
     }
 }
 
+/// Panics if the bytes of the saved `document` do not contain `needle`.
+pub fn assert_pdf_contains(document: &mut Document, needle: &[u8]) {
+    let mut actual = vec![];
+    assert!(document.save(&mut actual).is_ok());
+    if !actual.windows(needle.len()).any(|w| w == needle) {
+        panic!("PDF does not contain {:?}", String::from_utf8_lossy(needle));
+    }
+}
+
 /// Returns the path to a file in the test fixtures directory.
 pub fn fixture_path(file_name: &str) -> String {
     format!("{}/tests/fixtures/{}", env::var("CARGO_MANIFEST_DIR").unwrap(), file_name)